@@ -0,0 +1,86 @@
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+use crate::Solution;
+use crate::grid::Grid;
+use crate::render::{Color, Picture};
+
+/*
+Animations of the solvers in the terminal: a day shows frames as it goes,
+the player redraws them in place with 24-bit ANSI colors.
+*/
+
+pub trait Animate: Solution {
+    fn animate(input: &Self::Input, show: &mut dyn FnMut(&Picture, &str));
+}
+
+pub fn to_ansi(cells: &Grid<Color>) -> String {
+    /*
+    Two rows of cells per line of text: the upper half block is drawn in the color
+    of the top cell, over the color of the bottom one. Colors are only set when they change.
+    */
+    let mut res = String::new();
+    for top in (0..cells.height()).step_by(2) {
+        let mut current: Option<(Color, Color)> = None;
+        for col in 0..cells.width() {
+            let colors = (cells[(top, col)], *cells.get(top+1, col).unwrap_or(&Color::BLACK));
+            if current != Some(colors) {
+                let (fg, bg) = colors;
+                res += &format!("\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m", fg.r, fg.g, fg.b, bg.r, bg.g, bg.b);
+                current = Some(colors);
+            }
+            res.push('▀');
+        }
+        res += "\x1b[0m\n";
+    }
+    return res;
+}
+
+pub struct Player {
+    pub fps: f64,
+    pub step: bool, // wait for Enter between the frames
+    frame: usize,
+}
+
+impl Player {
+    pub fn new(fps: f64, step: bool) -> Player {
+        Player{fps, step, frame: 0}
+    }
+
+    pub fn show(&mut self, picture: &Picture, caption: &str) {
+        // the first frame clears the screen, the next ones are drawn over it
+        let mut out = String::from(if self.frame == 0 { "\x1b[2J\x1b[H" } else { "\x1b[H" });
+        out += &to_ansi(&picture.flatten());
+        out += &format!("\x1b[2K{} (frame {})\n", caption, self.frame + 1);
+        self.frame += 1;
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(out.as_bytes()).and_then(|_| stdout.flush()).expect("Could not write to the terminal");
+        if self.step {
+            let mut line = String::new();
+            io::stdin().lock().read_line(&mut line).expect("Could not read from the terminal");
+        } else {
+            thread::sleep(Duration::from_secs_f64(1.0 / self.fps));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ansi() {
+        let red = Color::new(255, 0, 0);
+        let mut cells = Grid::new(3, 2, Color::WHITE);
+        cells[(1, 1)] = red;
+
+        let ansi = to_ansi(&cells);
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "\x1b[38;2;255;255;255m\x1b[48;2;255;255;255m▀\x1b[38;2;255;255;255m\x1b[48;2;255;0;0m▀\x1b[0m");
+        // the odd last row is drawn over black
+        assert_eq!(lines[1], "\x1b[38;2;255;255;255m\x1b[48;2;0;0;0m▀▀\x1b[0m");
+    }
+}
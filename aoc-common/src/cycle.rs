@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/*
+Cycle detection for states iterated with a step function, which must eventually loop
+(e.g. a finite state space): both finders never return otherwise.
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // the states from step `prefix` on repeat every `period` steps
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        // the first step reaching the same state as step n
+        if n < self.prefix {
+            return n;
+        }
+        return self.prefix + (n - self.prefix) % self.period;
+    }
+
+    pub fn nth_state<S: Clone>(&self, initial: &S, step: impl Fn(&S) -> S, n: usize) -> S {
+        // skips the whole loops, so that n can be huge
+        let mut current = initial.clone();
+        for _ in 0..self.equivalent_step(n) {
+            current = step(&current);
+        }
+        return current;
+    }
+}
+
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: &S, step: impl Fn(&S) -> S) -> Cycle {
+    // remembers every state, with the step it was first seen at
+    let mut known: HashMap<S, usize> = HashMap::new();
+    let mut current = initial.clone();
+    let mut steps = 0;
+    while !known.contains_key(&current) {
+        let next = step(&current);
+        known.insert(current, steps);
+        current = next;
+        steps += 1;
+    }
+
+    let prefix = known[&current];
+    return Cycle{prefix, period: steps - prefix};
+}
+
+pub fn find_cycle_brent<S: Clone + Eq>(initial: &S, step: impl Fn(&S) -> S) -> Cycle {
+    /*
+    Brent's algorithm, which only keeps two states at once: a few more steps than find_cycle,
+    but no memory growth for large states.
+    */
+
+    // the period first: the hare runs ahead of a tortoise teleported at each power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // then the prefix: with the hare one period ahead, both meet at the start of the loop
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    return Cycle{prefix, period};
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn brute_nth(next: &Vec<usize>, start: usize, n: usize) -> usize {
+        (0..n).fold(start, |x, _| next[x])
+    }
+
+    #[test]
+    fn test_rho() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let next = vec![1, 2, 3, 4, 2];
+        let expected = Cycle{prefix: 2, period: 3};
+        assert_eq!(find_cycle(&0, |x| next[*x]), expected);
+        assert_eq!(find_cycle_brent(&0, |x| next[*x]), expected);
+        assert_eq!(expected.nth_state(&0, |x| next[*x], 1_000_000_000), brute_nth(&next, 0, 2 + (1_000_000_000 - 2) % 3));
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        assert_eq!(find_cycle(&7, |_| 7), Cycle{prefix: 0, period: 1});
+        assert_eq!(find_cycle_brent(&7, |_| 7), Cycle{prefix: 0, period: 1});
+    }
+
+    proptest! {
+        #[test]
+        fn prop_backends_agree(next in prop::collection::vec(0usize..40, 40), start in 0usize..40, n in 0usize..200) {
+            let cycle = find_cycle(&start, |x| next[*x]);
+            prop_assert_eq!(find_cycle_brent(&start, |x| next[*x]), cycle);
+            prop_assert!(cycle.period > 0);
+
+            // the loop does come back to its start, and no sooner state does
+            let loop_start = brute_nth(&next, start, cycle.prefix);
+            prop_assert_eq!(brute_nth(&next, loop_start, cycle.period), loop_start);
+            prop_assert!((0..cycle.prefix).all(|i| brute_nth(&next, start, i) != brute_nth(&next, start, i + cycle.period)));
+
+            prop_assert_eq!(cycle.nth_state(&start, |x| next[*x], n), brute_nth(&next, start, n));
+        }
+    }
+}
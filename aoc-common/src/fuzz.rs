@@ -0,0 +1,123 @@
+use std::panic;
+use crate::Solution;
+use crate::rng::Rng;
+
+/*
+Helpers for the fuzz tests of the parsers: whatever the input, a parser should
+return a ParseError rather than panic.
+*/
+
+// characters the parsers split on or trip over, added to the ones of the input
+const TRICKY: [char; 16] = ['\n', ' ', ':', ',', '=', '-', '#', '.', '(', ')', '{', '}', '0', '9', 'é', '\u{feff}'];
+
+pub fn parse_panic<S: Solution>(input: &str) -> Option<String> {
+    // the message of the panic of the parser on this input, if any
+    let res = panic::catch_unwind(|| { let _ = S::parse(input); });
+    return res.err().map(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "panicked".to_string()
+        }
+    });
+}
+
+pub fn mutate(rng: &mut Rng, input: &str, edits: usize) -> String {
+    /*
+    A few random edits of a valid input: characters removed, replaced or inserted,
+    lines dropped or repeated, the end cut off.
+    Works on chars, so that multi-byte characters can be inserted anywhere.
+    */
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut alphabet: Vec<char> = TRICKY.to_vec();
+    alphabet.extend(chars.iter().copied());
+
+    for _ in 0..edits {
+        let pos = rng.below(chars.len() + 1);
+        match rng.below(6) {
+            0 if pos < chars.len() => { chars.remove(pos); },
+            1 if pos < chars.len() => chars[pos] = *rng.choose(&alphabet),
+            2 => chars.insert(pos, *rng.choose(&alphabet)),
+            3 | 4 => {
+                // the line around pos, dropped or repeated
+                let start = chars[..pos].iter().rposition(|c| *c == '\n').map_or(0, |i| i+1);
+                let end = chars[pos..].iter().position(|c| *c == '\n').map_or(chars.len(), |i| pos+i+1);
+                let line: Vec<char> = chars.drain(start..end).collect();
+                if rng.chance(0.5) {
+                    chars.splice(start..start, line.iter().chain(line.iter()).copied());
+                }
+            },
+            _ => chars.truncate(pos),
+        }
+    }
+    return chars.into_iter().collect();
+}
+
+#[macro_export]
+macro_rules! fuzz_parse {
+    /*
+    The fuzz tests of a day's parser, for its tests module: randomly edited generated
+    inputs, and random strings. The day's crate needs proptest as a dev-dependency.
+    */
+    ($day:ty) => {
+        proptest::proptest! {
+            #[test]
+            fn prop_parse_mutated(seed: u64, edits in 0..10usize) {
+                let mut rng = $crate::rng::Rng::new(seed);
+                let valid = <$day as $crate::Generator>::generate(&mut rng, 5);
+                let input = $crate::fuzz::mutate(&mut rng, &valid, edits);
+                proptest::prop_assert_eq!($crate::fuzz::parse_panic::<$day>(&input), None, "on the input:\n{}", input);
+            }
+
+            #[test]
+            fn prop_parse_arbitrary(input in "(\\PC|\n){0,100}") {
+                proptest::prop_assert_eq!($crate::fuzz::parse_panic::<$day>(&input), None, "on the input:\n{}", input);
+            }
+        }
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParseError;
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(contents: &str) -> Result<Vec<u32>, ParseError> {
+            Ok(contents.lines().map(|l| l.parse().unwrap()).collect())
+        }
+        fn part1(input: &Vec<u32>) -> u32 {
+            input.iter().sum()
+        }
+        fn part2(input: &Vec<u32>) -> u32 {
+            input.iter().product()
+        }
+    }
+
+    #[test]
+    fn test_parse_panic() {
+        assert_eq!(parse_panic::<Numbers>("1\n2\n"), None);
+        assert!(parse_panic::<Numbers>("1\nx\n").unwrap().contains("ParseIntError"));
+    }
+
+    #[test]
+    fn test_mutate() {
+        let input = "abc\ndef\n";
+        assert_eq!(mutate(&mut Rng::new(0), input, 0), input);
+        // same seed, same edits
+        for seed in 0..20 {
+            assert_eq!(mutate(&mut Rng::new(seed), input, 3), mutate(&mut Rng::new(seed), input, 3));
+        }
+        assert!((0..20).any(|seed| mutate(&mut Rng::new(seed), input, 3) != input));
+    }
+}
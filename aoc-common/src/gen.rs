@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use crate::geometry::{Direction, Point};
+use crate::rng::Rng;
+
+/*
+Building blocks shared by the input generators of the days.
+*/
+
+pub fn random_word(rng: &mut Rng, len: usize, letters: &str) -> String {
+    let letters: Vec<char> = letters.chars().collect();
+    (0..len).map(|_| *rng.choose(&letters)).collect()
+}
+
+pub fn random_loop(rng: &mut Rng, rows: usize, cols: usize, scale: usize) -> Vec<Point> {
+    /*
+    A random simple closed loop, as the corners of unit steps from one to the next.
+    It outlines a random spanning tree of a rows x cols grid, drawn with cells of `scale` tiles:
+    the tree has no cycle so the shape has no hole, and the shape never touches itself
+    by a corner only since every node of the tree is drawn.
+    The points fit in [0, 2*rows*scale - scale] x [0, 2*cols*scale - scale].
+    */
+    assert!(rows > 0 && cols > 0 && scale > 0);
+
+    // random depth-first spanning tree, drawn on a grid of twice the size
+    let (height, width) = (2*rows - 1, 2*cols - 1);
+    let mut drawn = vec![vec![false; width]; height];
+    let mut visited = vec![vec![false; cols]; rows];
+    let mut stack = vec![(rng.below(rows), rng.below(cols))];
+    visited[stack[0].0][stack[0].1] = true;
+    drawn[2*stack[0].0][2*stack[0].1] = true;
+    while let Some(&(r, c)) = stack.last() {
+        let mut next: Vec<(usize, usize)> = vec![];
+        for dir in Direction::ALL {
+            let (nr, nc) = (r as i64 + dir.delta().row, c as i64 + dir.delta().col);
+            if nr >= 0 && nc >= 0 && (nr as usize) < rows && (nc as usize) < cols && !visited[nr as usize][nc as usize] {
+                next.push((nr as usize, nc as usize));
+            }
+        }
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (nr, nc) = *rng.choose(&next);
+        visited[nr][nc] = true;
+        drawn[2*nr][2*nc] = true;
+        drawn[r + nr][c + nc] = true;
+        stack.push((nr, nc));
+    }
+
+    let is_drawn = |r: i64, c: i64| {
+        r >= 0 && c >= 0 && (r as usize) < height*scale && (c as usize) < width*scale
+            && drawn[r as usize / scale][c as usize / scale]
+    };
+
+    // each drawn tile side facing an empty tile is an edge of the outline, turning clockwise
+    let mut next_corner: HashMap<Point, Point> = HashMap::new();
+    for r in 0..(height*scale) as i64 {
+        for c in 0..(width*scale) as i64 {
+            if !is_drawn(r, c) {
+                continue;
+            }
+            if !is_drawn(r-1, c) {
+                next_corner.insert(Point::new(r, c), Point::new(r, c+1));
+            }
+            if !is_drawn(r, c+1) {
+                next_corner.insert(Point::new(r, c+1), Point::new(r+1, c+1));
+            }
+            if !is_drawn(r+1, c) {
+                next_corner.insert(Point::new(r+1, c+1), Point::new(r+1, c));
+            }
+            if !is_drawn(r, c-1) {
+                next_corner.insert(Point::new(r+1, c), Point::new(r, c));
+            }
+        }
+    }
+
+    let start = *next_corner.keys().min().unwrap();
+    let mut res = vec![start];
+    let mut current = next_corner[&start];
+    while current != start {
+        res.push(current);
+        current = next_corner[&current];
+    }
+    return res;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_random_loop() {
+        for seed in 0..20 {
+            let mut rng = Rng::new(seed);
+            let corners = random_loop(&mut rng, 1 + seed as usize % 4, 1 + seed as usize % 5, 1 + seed as usize % 3);
+
+            // unit steps, closed, never visiting a corner twice
+            for (i, p) in corners.iter().enumerate() {
+                assert_eq!(p.manhattan(corners[(i+1) % corners.len()]), 1);
+            }
+            assert_eq!(corners.iter().collect::<HashSet<_>>().len(), corners.len());
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Copy,Clone,Eq,PartialEq,Hash,Debug,PartialOrd,Ord,Default)]
+pub struct Point {
+    // a signed position, like the (row, column) indexes of a Grid: rows grow downwards
+    pub row: i64,
+    pub col: i64,
+}
+
+#[derive(Copy,Clone,Eq,PartialEq,Hash,Debug,PartialOrd,Ord)]
+pub enum Direction {
+    Right=0,
+    Left=1,
+    Bottom=2,
+    Top=3,
+}
+
+#[derive(Copy,Clone,Eq,PartialEq,Debug)]
+pub struct BoundingBox {
+    // both corners are inside the box
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Point {
+    pub fn new(row: i64, col: i64) -> Point {
+        Point{row, col}
+    }
+
+    pub fn step(self, dir: Direction) -> Point {
+        self + dir.delta()
+    }
+
+    pub fn step_n(self, dir: Direction, n: i64) -> Point {
+        self + dir.delta()*n
+    }
+
+    pub fn checked_step(self, dir: Direction, bounds: &BoundingBox) -> Option<Point> {
+        // None when stepping out of the box
+        let next = self.step(dir);
+        if bounds.contains(next) {
+            return Some(next);
+        }
+        return None;
+    }
+
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.row-other.row).abs() + (self.col-other.col).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point{row: self.row+other.row, col: self.col+other.col}
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point{row: self.row-other.row, col: self.col-other.col}
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i64) -> Point {
+        Point{row: self.row*k, col: self.col*k}
+    }
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Right, Direction::Left, Direction::Bottom, Direction::Top];
+
+    pub fn from_char(c: char) -> Option<Direction> {
+        // "URDL" letters or arrows
+        match c {
+            'R' | '>' | '→' => Some(Direction::Right),
+            'L' | '<' | '←' => Some(Direction::Left),
+            'D' | 'v' | '↓' => Some(Direction::Bottom),
+            'U' | '^' | '↑' => Some(Direction::Top),
+            _ => None,
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Top => Direction::Bottom,
+            Direction::Bottom => Direction::Top,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        // counter-clockwise, as seen on a map
+        match self {
+            Direction::Right => Direction::Top,
+            Direction::Top => Direction::Left,
+            Direction::Left => Direction::Bottom,
+            Direction::Bottom => Direction::Right,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().reverse()
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Right => Point::new(0, 1),
+            Direction::Left => Point::new(0, -1),
+            Direction::Bottom => Point::new(1, 0),
+            Direction::Top => Point::new(-1, 0),
+        }
+    }
+}
+
+impl BoundingBox {
+    pub fn around(p: Point) -> BoundingBox {
+        BoundingBox{min: p, max: p}
+    }
+
+    pub fn from_size(height: usize, width: usize) -> BoundingBox {
+        // the cells of a grid, from (0, 0)
+        BoundingBox{min: Point::new(0, 0), max: Point::new(height as i64 - 1, width as i64 - 1)}
+    }
+
+    pub fn extend(&mut self, p: Point) {
+        self.min = Point::new(self.min.row.min(p.row), self.min.col.min(p.col));
+        self.max = Point::new(self.max.row.max(p.row), self.max.col.max(p.col));
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.min.row <= p.row && p.row <= self.max.row && self.min.col <= p.col && p.col <= self.max.col
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.row - self.min.row + 1
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.col - self.min.col + 1
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_twice() {
+        for dir in Direction::ALL {
+            assert_ne!(dir.reverse(), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+        }
+    }
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_left().turn_left(), dir.reverse());
+            // a quarter turn swaps the axes
+            assert_eq!(dir.turn_left().delta().row.abs(), dir.delta().col.abs());
+        }
+        assert_eq!(Direction::Top.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn test_delta_reverse() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point::new(0, 0));
+        }
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!("URDL".chars().map(Direction::from_char).collect::<Vec<_>>(),
+                   "^>v<".chars().map(Direction::from_char).collect::<Vec<_>>());
+        assert_eq!(Direction::from_char('D'), Some(Direction::Bottom));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_step_inside() {
+        let bounds = BoundingBox::from_size(3, 3);
+        let p = Point::new(1, 1);
+        assert_eq!(p.checked_step(Direction::Top, &bounds), Some(Point::new(0, 1)));
+        assert_eq!(p.checked_step(Direction::Left, &bounds), Some(Point::new(1, 0)));
+        assert_eq!(p.checked_step(Direction::Bottom, &bounds), Some(Point::new(2, 1)));
+        assert_eq!(p.checked_step(Direction::Right, &bounds), Some(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_step_borders() {
+        // 2 rows, 3 columns
+        let bounds = BoundingBox::from_size(2, 3);
+        assert_eq!(Point::new(0, 0).checked_step(Direction::Top, &bounds), None);
+        assert_eq!(Point::new(0, 0).checked_step(Direction::Left, &bounds), None);
+        assert_eq!(Point::new(1, 2).checked_step(Direction::Bottom, &bounds), None);
+        assert_eq!(Point::new(1, 2).checked_step(Direction::Right, &bounds), None);
+    }
+
+    #[test]
+    fn test_bounding_box_extend() {
+        let mut bounds = BoundingBox::around(Point::new(0, 0));
+        for p in [Point::new(0, 6), Point::new(5, 6), Point::new(-2, 1)] {
+            bounds.extend(p);
+        }
+        assert_eq!((bounds.min, bounds.max), (Point::new(-2, 0), Point::new(5, 6)));
+        assert_eq!((bounds.height(), bounds.width()), (8, 7));
+        assert_eq!(Point::new(0, 0).step_n(Direction::Right, 6).manhattan(Point::new(5, 6)), 5);
+    }
+}
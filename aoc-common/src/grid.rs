@@ -0,0 +1,329 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use crate::ParseError;
+use crate::geometry::{BoundingBox, Point};
+use crate::parse::{grid_lines, Line};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    /*
+    A rectangular grid stored row by row in a single Vec.
+    Positions are always (row, column), with (0, 0) the top-left cell, like a geometry Point.
+    */
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+pub struct GridDisplay<'a, T, F: Fn(&T) -> char> {
+    grid: &'a Grid<T>,
+    to_char: F,
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        // all rows should have the same length
+        let height = rows.len();
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(height*width);
+        for row in rows {
+            assert_eq!(row.len(), width, "grid rows should all have the same length");
+            cells.extend(row);
+        }
+        return Grid{height, width, cells};
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(usize, usize) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(height*width);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(f(row, col));
+            }
+        }
+        return Grid{height, width, cells};
+    }
+
+    pub fn from_lines(lines: &[Line], f: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        /*
+        A character map, one line per row. Every line should be as wide as the first one,
+        and `f` returns None for the characters which are not allowed.
+        */
+        let width = match lines.first() {
+            Some(line) => line.chars().count(),
+            None => return Err(ParseError::new("expected a grid, found nothing")),
+        };
+        let mut cells = Vec::with_capacity(lines.len()*width);
+        for line in lines {
+            line.check_width(width)?;
+            for (pos, c) in line.text.char_indices() {
+                match f(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(line.error(&line.text[pos..(pos+c.len_utf8())], &format!("unexpected character `{}`", c))),
+                }
+            }
+        }
+        return Ok(Grid{height: lines.len(), width, cells});
+    }
+
+    pub fn parse(contents: &str, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        // the input has a single grid, followed by empty lines at most
+        return Grid::from_lines(&grid_lines(contents)?, f);
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn bounds(&self) -> BoundingBox {
+        BoundingBox::from_size(self.height, self.width)
+    }
+
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        return row >= 0 && col >= 0 && (row as usize) < self.height && (col as usize) < self.width;
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        return Some(&self.cells[row*self.width + col]);
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        return Some(&mut self.cells[row*self.width + col]);
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[(row*self.width)..((row+1)*self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() does not accept a width of 0
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        // every cell along with its position, in reading order
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, v)| ((i/width, i%width), v))
+    }
+
+    pub fn neighbours4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        // up, left, right, down, only those inside the grid
+        const DELTAS: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+        self.neighbours(row, col, &DELTAS)
+    }
+
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        // the 4 neighbours and the diagonals, in reading order
+        const DELTAS: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+        self.neighbours(row, col, &DELTAS)
+    }
+
+    fn neighbours(&self, row: usize, col: usize, deltas: &'static [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        deltas.iter()
+            .map(move |(dr, dc)| (row as isize + dr, col as isize + dc))
+            .filter(|(r, c)| self.contains(*r, *c))
+            .map(|(r, c)| (r as usize, c as usize))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid{height: self.height, width: self.width, cells: self.cells.iter().map(f).collect()};
+    }
+
+    pub fn display<F: Fn(&T) -> char>(&self, to_char: F) -> GridDisplay<'_, T, F> {
+        // e.g. println!("{}", grid.display(|b| if *b { '#' } else { '.' }));
+        GridDisplay{grid: self, to_char}
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T> {
+        return Grid{height, width, cells: vec![value; height*width]};
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        // rows become columns
+        return Grid::from_fn(self.width, self.height, |row, col| self[(col, row)].clone());
+    }
+
+    pub fn rotate_cw(&self) -> Grid<T> {
+        // the left column becomes the top row
+        return Grid::from_fn(self.width, self.height, |row, col| self[(self.height-1-col, row)].clone());
+    }
+
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        // the top row becomes the left column
+        return Grid::from_fn(self.width, self.height, |row, col| self[(col, self.width-1-row)].clone());
+    }
+
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        // mirror left/right
+        return Grid::from_fn(self.height, self.width, |row, col| self[(row, self.width-1-col)].clone());
+    }
+
+    pub fn flip_vertical(&self) -> Grid<T> {
+        // mirror up/down
+        return Grid::from_fn(self.height, self.width, |row, col| self[(self.height-1-row, col)].clone());
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(row < self.height && col < self.width, "({}, {}) is outside of a {}x{} grid", row, col, self.height, self.width);
+        &self.cells[row*self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(row < self.height && col < self.width, "({}, {}) is outside of a {}x{} grid", row, col, self.height, self.width);
+        &mut self.cells[row*self.width + col]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        assert!(p.row >= 0 && p.col >= 0, "{:?} is outside of the grid", p);
+        &self[(p.row as usize, p.col as usize)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        assert!(p.row >= 0 && p.col >= 0, "{:?} is outside of the grid", p);
+        &mut self[(p.row as usize, p.col as usize)]
+    }
+}
+
+impl<'a, T, F: Fn(&T) -> char> fmt::Display for GridDisplay<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.grid.rows() {
+            let line: String = row.iter().map(&self.to_char).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small() -> Grid<i32> {
+        // 1 2 3
+        // 4 5 6
+        Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    fn to_rows<T: Clone>(g: &Grid<T>) -> Vec<Vec<T>> {
+        g.rows().map(|r| r.to_vec()).collect()
+    }
+
+    #[test]
+    fn test_index_row_major() {
+        let g = small();
+        assert_eq!((g.height(), g.width()), (2, 3));
+        assert_eq!(g[(1, 0)], 4);
+        assert_eq!(g.get(0, 2), Some(&3));
+        assert_eq!(g.get(2, 0), None);
+        assert_eq!(g.get(0, 3), None);
+        assert_eq!(g[Point::new(1, 2)], 6);
+        assert!(g.bounds().contains(Point::new(1, 2)) && !g.bounds().contains(Point::new(2, 0)));
+    }
+
+    #[test]
+    fn test_new_filled() {
+        let mut g = Grid::new(2, 2, 0);
+        g[(0, 1)] = 7;
+        *g.get_mut(1, 0).unwrap() = 8;
+        assert_eq!(to_rows(&g), vec![vec![0, 7], vec![8, 0]]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(to_rows(&small().transpose()), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(small().transpose().transpose(), small());
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(to_rows(&small().rotate_cw()), vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+        assert_eq!(to_rows(&small().rotate_ccw()), vec![vec![3, 6], vec![2, 5], vec![1, 4]]);
+        assert_eq!(small().rotate_cw().rotate_ccw(), small());
+        assert_eq!(small().rotate_cw().rotate_cw().rotate_cw().rotate_cw(), small());
+    }
+
+    #[test]
+    fn test_flip() {
+        assert_eq!(to_rows(&small().flip_horizontal()), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+        assert_eq!(to_rows(&small().flip_vertical()), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        // a half turn is both flips
+        assert_eq!(small().rotate_cw().rotate_cw(), small().flip_horizontal().flip_vertical());
+    }
+
+    #[test]
+    fn test_row_and_column_views() {
+        let g = small();
+        assert_eq!(g.row(1), &[4, 5, 6]);
+        assert_eq!(g.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        let sums: Vec<i32> = g.columns().map(|c| c.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn test_iter_positions() {
+        let positions: Vec<(usize, usize)> = small().iter().filter(|(_, v)| **v % 2 == 0).map(|(p, _)| p).collect();
+        assert_eq!(positions, vec![(0, 1), (1, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn test_neighbours_bounds() {
+        let g = small();
+        assert_eq!(g.neighbours4(0, 0).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(g.neighbours4(1, 1).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 2)]);
+        assert_eq!(g.neighbours8(0, 1).collect::<Vec<_>>(), vec![(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+        assert_eq!(g.neighbours8(1, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let g = Grid::parse("#.\n.#\n\n", |c| match c { '#' => Some(true), '.' => Some(false), _ => None }).unwrap();
+        assert_eq!(to_rows(&g), vec![vec![true, false], vec![false, true]]);
+        assert_eq!(g.display(|b| if *b { '#' } else { ' ' }).to_string(), "# \n #\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let bit = |c| match c { '0' => Some(0), '1' => Some(1), _ => None };
+        let e = Grid::parse("01\n0x\n", bit).unwrap_err();
+        assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "unexpected character `x`"));
+        let e = Grid::parse("01\n010\n", bit).unwrap_err();
+        assert_eq!(e.line, 2);
+        assert!(Grid::parse("\n", bit).is_err());
+        let e = Grid::parse("01\n\n10\n", bit).unwrap_err();
+        assert_eq!(e.line, 2);
+        assert!(Grid::parse("\n01\n", bit).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::process;
+use crate::Solution;
+use crate::output::{print_json, Format};
+use crate::parse::normalize;
+
+pub const STDIN_NAME: &str = "-";
+
+pub fn read_input(filename: &str) -> io::Result<String> {
+    // "-" reads the whole standard input, either way the contents are normalized for the parsers
+    let contents = if filename == STDIN_NAME {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(filename)?
+    };
+    return Ok(normalize(&contents));
+}
+
+pub fn input_names(args: &[String]) -> Vec<String> {
+    /*
+    The input files given to a day's binary, every argument after the program name.
+    Exits with a usage message when there is none.
+    */
+    if args.len() < 2 {
+        let program = args.first().map(|s| s.as_str()).unwrap_or("aoc");
+        eprintln!("Usage: {} [--format <text|json>] <input|-> [input...]", program);
+        process::exit(2);
+    }
+    return args[1..].to_vec();
+}
+
+pub fn try_read(filename: &str) -> Option<String> {
+    match read_input(filename) {
+        Ok(contents) => Some(contents),
+        Err(e) => {
+            eprintln!("error: could not read {}: {}", filename, e);
+            None
+        }
+    }
+}
+
+pub fn try_parse<S: Solution>(contents: &str, filename: &str) -> Option<S::Input> {
+    // prints the diagnostic on failure, so that the other inputs can still be run
+    match S::parse(contents) {
+        Ok(input) => Some(input),
+        Err(e) => {
+            eprintln!("{}", e.with_file(filename));
+            None
+        }
+    }
+}
+
+pub fn run_main<S: Solution>(args: Vec<String>, show: impl Fn(&S::Input)) {
+    /*
+    Shared main of the day binaries: runs every input given on the command line,
+    "-" being stdin. The other inputs are still run when one cannot be read or parsed,
+    the exit code tells that something failed.
+    */
+    let mut args = args;
+    let format = Format::take_from_args(&mut args);
+    let filenames = input_names(&args);
+
+    let mut failed = false;
+    for filename in &filenames {
+        let contents = match try_read(filename) {
+            Some(contents) => contents,
+            None => {
+                failed = true;
+                continue;
+            }
+        };
+
+        if format == Format::Json {
+            // records carry the input name already
+            failed |= !print_json::<S>(&contents, filename);
+            continue;
+        }
+
+        if filenames.len() > 1 {
+            println!("== {}", filename);
+        }
+        match try_parse::<S>(&contents, filename) {
+            Some(input) => show(&input),
+            None => failed = true,
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_names() {
+        let args: Vec<String> = vec!["aoc05".to_string(), "a".to_string(), "-".to_string()];
+        assert_eq!(input_names(&args), vec!["a".to_string(), "-".to_string()]);
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        assert!(read_input("this/file/does/not/exist").is_err());
+    }
+}
@@ -0,0 +1,310 @@
+use std::ops::{Add, Mul, Sub};
+use num_traits::{One, Zero};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    // half-open, [start, end[, empty when end <= start
+    pub start: T,
+    pub end: T,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /*
+    Always kept normalized: sorted, without empty intervals, and neither overlapping
+    nor touching, so that two sets with the same values are equal.
+    */
+    intervals: Vec<Interval<T>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    // an interval on each axis, e.g. the ranges of x, m, a and s in aoc19
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval{start, end}
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let res = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        if res.is_empty() {
+            return None;
+        }
+        return Some(res);
+    }
+
+    pub fn split_at(&self, at: T) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        // the parts below `at` and from `at`, None when a part is empty
+        let below = Interval::new(self.start, self.end.min(at));
+        let above = Interval::new(self.start.max(at), self.end);
+        return (
+            if below.is_empty() { None } else { Some(below) },
+            if above.is_empty() { None } else { Some(above) },
+        );
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Zero> Interval<T> {
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        return self.end - self.start;
+    }
+}
+
+impl<T: Copy + Add<Output = T>> Interval<T> {
+    pub fn shift(&self, offset: T) -> Interval<T> {
+        Interval{start: self.start + offset, end: self.end + offset}
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet{intervals: vec![]}
+    }
+
+    fn normalized(mut intervals: Vec<Interval<T>>) -> IntervalSet<T> {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort();
+
+        let mut res: Vec<Interval<T>> = vec![];
+        for i in intervals {
+            match res.last_mut() {
+                // overlapping or touching, merge
+                Some(last) if i.start <= last.end => last.end = last.end.max(i.end),
+                _ => res.push(i),
+            }
+        }
+        return IntervalSet{intervals: res};
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        // intervals are sorted, find the last one starting at or before x
+        let pos = self.intervals.partition_point(|i| i.start <= x);
+        return pos > 0 && self.intervals[pos-1].contains(x);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::normalized(self.intervals.iter().chain(other.intervals.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // both are sorted, walk them together
+        let mut res: Vec<Interval<T>> = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            if let Some(common) = a.intersection(b) {
+                res.push(common);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        return IntervalSet::normalized(res);
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        // the values of self which are not in other
+        let mut res: Vec<Interval<T>> = vec![];
+        for a in &self.intervals {
+            let mut remaining = Some(*a);
+            for b in other.intervals.iter().filter(|b| b.end > a.start && b.start < a.end) {
+                let current = match remaining {
+                    Some(current) => current,
+                    None => break,
+                };
+                let (below, _) = current.split_at(b.start);
+                let (_, above) = current.split_at(b.end);
+                if let Some(below) = below {
+                    res.push(below);
+                }
+                remaining = above;
+            }
+            if let Some(rest) = remaining {
+                res.push(rest);
+            }
+        }
+        return IntervalSet::normalized(res);
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Zero> IntervalSet<T> {
+    pub fn len(&self) -> T {
+        // count of values in the set
+        self.intervals.iter().fold(T::zero(), |acc, i| acc + i.len())
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> IntervalSet<T> {
+        IntervalSet::normalized(iter.into_iter().collect())
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Cuboid<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Cuboid<T, N> {
+        Cuboid{axes}
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|i| i.is_empty())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.axes.iter().zip(point.iter()).all(|(i, x)| i.contains(*x))
+    }
+
+    pub fn intersection(&self, other: &Cuboid<T, N>) -> Option<Cuboid<T, N>> {
+        let mut axes = self.axes;
+        for (axis, o) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(o)?;
+        }
+        return Some(Cuboid{axes});
+    }
+
+    pub fn split(&self, axis: usize, at: T) -> (Option<Cuboid<T, N>>, Option<Cuboid<T, N>>) {
+        // cut by the hyperplane axis = at, into the parts below it and from it
+        let (below, above) = self.axes[axis].split_at(at);
+        let with_axis = |i: Interval<T>| {
+            let mut axes = self.axes;
+            axes[axis] = i;
+            Cuboid{axes}
+        };
+        return (below.map(with_axis), above.map(with_axis));
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Mul<Output = T> + Zero + One, const N: usize> Cuboid<T, N> {
+    pub fn volume(&self) -> T {
+        self.axes.iter().fold(T::one(), |acc, i| acc * i.len())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn set(v: &[(i32, i32)]) -> IntervalSet<i32> {
+        v.iter().map(|(a, b)| Interval::new(*a, *b)).collect()
+    }
+
+    fn members(s: &IntervalSet<i32>) -> Vec<i32> {
+        (-5..80).filter(|x| s.contains(*x)).collect()
+    }
+
+    fn is_normalized(s: &IntervalSet<i32>) -> bool {
+        s.iter().all(|i| !i.is_empty()) && s.intervals.windows(2).all(|w| w[0].end < w[1].start)
+    }
+
+    fn arb_set() -> impl Strategy<Value = IntervalSet<i32>> {
+        prop::collection::vec((0i32..64, 0i32..12), 0..6)
+            .prop_map(|v| v.iter().map(|(a, l)| Interval::new(*a, a + l)).collect())
+    }
+
+    #[test]
+    fn test_interval_split() {
+        let i = Interval::new(1, 10);
+        assert_eq!(i.split_at(4), (Some(Interval::new(1, 4)), Some(Interval::new(4, 10))));
+        assert_eq!(i.split_at(1), (None, Some(i)));
+        assert_eq!(i.split_at(12), (Some(i), None));
+        assert_eq!(i.len(), 9);
+        assert_eq!(Interval::new(5, 2).len(), 0);
+    }
+
+    #[test]
+    fn test_set_merges_touching() {
+        let s = set(&[(5, 8), (1, 3), (3, 4), (7, 10), (20, 20)]);
+        assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![Interval::new(1, 4), Interval::new(5, 10)]);
+        assert_eq!(s.len(), 8);
+        assert_eq!(s.min(), Some(1));
+    }
+
+    #[test]
+    fn test_set_difference() {
+        let s = set(&[(0, 10), (20, 30)]).difference(&set(&[(2, 4), (8, 22), (25, 26)]));
+        assert_eq!(s, set(&[(0, 2), (4, 8), (22, 25), (26, 30)]));
+    }
+
+    #[test]
+    fn test_box_split_volume() {
+        let b = Cuboid::new([Interval::new(1usize, 4001); 4]);
+        assert_eq!(b.volume(), 4000usize.pow(4));
+        let (below, above) = b.split(0, 2006);
+        let (below, above) = (below.unwrap(), above.unwrap());
+        assert_eq!((below.axes[0], above.axes[0]), (Interval::new(1, 2006), Interval::new(2006, 4001)));
+        assert_eq!(below.axes[1], b.axes[1]);
+        assert_eq!(b.split(2, 5000), (Some(b), None));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_operations(a in arb_set(), b in arb_set()) {
+            let (ma, mb) = (members(&a), members(&b));
+            let union = a.union(&b);
+            let inter = a.intersection(&b);
+            let diff = a.difference(&b);
+            for s in [&a, &union, &inter, &diff] {
+                prop_assert!(is_normalized(s));
+            }
+            prop_assert_eq!(members(&union), (-5..80).filter(|x| ma.contains(x) || mb.contains(x)).collect::<Vec<_>>());
+            prop_assert_eq!(members(&inter), (-5..80).filter(|x| ma.contains(x) && mb.contains(x)).collect::<Vec<_>>());
+            prop_assert_eq!(members(&diff), (-5..80).filter(|x| ma.contains(x) && !mb.contains(x)).collect::<Vec<_>>());
+            prop_assert_eq!(union.len() as usize, members(&union).len());
+        }
+
+        #[test]
+        fn prop_box_split(x in (0i64..10, 0i64..10), y in (0i64..10, 0i64..10), z in (0i64..10, 0i64..10),
+                          axis in 0usize..3, at in -2i64..12, point in prop::array::uniform3(0i64..20)) {
+            let b = Cuboid::new([Interval::new(x.0, x.0+x.1), Interval::new(y.0, y.0+y.1), Interval::new(z.0, z.0+z.1)]);
+            let (below, above) = b.split(axis, at);
+            let volume = |p: Option<Cuboid<i64, 3>>| p.map(|p| p.volume()).unwrap_or(0);
+            prop_assert_eq!(volume(below) + volume(above), b.volume());
+            // every point of the cuboid is in exactly one part
+            let inside = |p: Option<Cuboid<i64, 3>>| p.map(|p| p.contains(&point)).unwrap_or(false);
+            prop_assert_eq!(inside(below) as u32 + inside(above) as u32, b.contains(&point) as u32);
+            prop_assert_eq!(b.volume(), (0..20).flat_map(|i| (0..20).flat_map(move |j| (0..20).map(move |k| [i, j, k])))
+                                               .filter(|p| b.contains(p)).count() as i64);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+use std::fmt::Display;
+use rng::Rng;
+
+pub mod animate;
+pub mod cycle;
+pub mod fuzz;
+pub mod gen;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod numtheory;
+pub mod output;
+pub mod parse;
+pub mod pipe;
+pub mod reference;
+pub mod render;
+pub mod rng;
+pub mod search;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub file: Option<String>,
+    pub line: usize, // 1-based, 0 when the error is not tied to a line
+    pub column: usize, // 1-based, in characters
+    pub len: usize, // length of the offending part of the snippet
+    pub snippet: String,
+}
+
+impl ParseError {
+    pub fn new(message: &str) -> ParseError {
+        ParseError{message: message.to_string(), file: None, line: 0, column: 0, len: 0, snippet: String::new()}
+    }
+
+    pub fn at(line: usize, snippet: &str, token: &str, message: &str) -> ParseError {
+        /*
+        Error pointing at 'token' inside 'snippet', the full text of the line.
+        The token should be a slice of the snippet, otherwise the whole line is pointed at.
+        */
+        let start = snippet.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+        let (column, len) = if pos >= start && pos + token.len() <= start + snippet.len() {
+            (snippet[..(pos - start)].chars().count() + 1, token.chars().count())
+        } else {
+            (1, snippet.chars().count())
+        };
+        ParseError{
+            message: message.to_string(),
+            file: None,
+            line,
+            column,
+            len,
+            snippet: snippet.to_string(),
+        }
+    }
+
+    pub fn with_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        /*
+        Rendered like rustc diagnostics:
+            error: expected a number, found `x1`
+             --> aoc09/input:3:7
+              |
+            3 | 10 13 x1 16
+              |       ^^
+        */
+        writeln!(f, "error: {}", self.message)?;
+        let file = self.file.as_deref().unwrap_or("<input>");
+        if self.line == 0 {
+            return write!(f, " --> {}", file);
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(self.len.max(1)))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub trait Solution {
+    /*
+    A day of the calendar, split in stages so that each can be called (and timed) on its own.
+    The input is parsed once, then both parts work from the parsed value.
+    */
+    const DAY: u32;
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(contents: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+pub enum MaybeAnswer<T> {
+    /*
+    For the parts which only work on inputs shaped like the puzzle's: the examples
+    of the statement, or random inputs, can have no answer rather than crash the runner.
+    */
+    Answer(T),
+    NoAnswer(String), // why the part does not apply
+}
+
+impl<T> From<Result<T, String>> for MaybeAnswer<T> {
+    fn from(res: Result<T, String>) -> MaybeAnswer<T> {
+        match res {
+            Ok(answer) => MaybeAnswer::Answer(answer),
+            Err(reason) => MaybeAnswer::NoAnswer(reason),
+        }
+    }
+}
+
+const NO_ANSWER: &str = "no answer";
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MaybeAnswer::Answer(answer) => write!(f, "{}", answer),
+            MaybeAnswer::NoAnswer(reason) => write!(f, "{} ({})", NO_ANSWER, reason),
+        }
+    }
+}
+
+pub fn is_no_answer(answer: &str) -> bool {
+    // the runner only sees the answers as text, and must not record these
+    answer.starts_with(&format!("{} (", NO_ANSWER))
+}
+
+pub trait Generator: Solution {
+    /*
+    Random valid inputs for a day, to stress the solver beyond the puzzle input.
+    The same seed gives the same input, and size roughly scales its line count.
+    */
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_column() {
+        let line = "Game 3: 8 purple";
+        let e = ParseError::at(3, line, &line[10..16], "unknown colour");
+        assert_eq!(e.column, 11);
+        assert_eq!(e.len, 6);
+    }
+
+    #[test]
+    fn test_error_foreign_token() {
+        let e = ParseError::at(1, "abc", "abc", "oops");
+        assert_eq!(e.column, 1);
+        assert_eq!(e.len, 3);
+    }
+
+    #[test]
+    fn test_error_display() {
+        let line = "10 13 x1 16";
+        let e = ParseError::at(3, line, &line[6..8], "expected a number, found `x1`").with_file("aoc09/input");
+        let expected = "error: expected a number, found `x1`\n --> aoc09/input:3:7\n  |\n3 | 10 13 x1 16\n  |       ^^";
+        assert_eq!(e.to_string(), expected);
+    }
+
+    #[test]
+    fn test_maybe_answer_display() {
+        assert_eq!(MaybeAnswer::from(Ok::<u32, String>(42)).to_string(), "42");
+        assert_eq!(MaybeAnswer::<u32>::from(Err("no rx".to_string())).to_string(), "no answer (no rx)");
+        assert!(is_no_answer(&MaybeAnswer::<u32>::from(Err("no rx".to_string())).to_string()));
+        assert!(!is_no_answer("42"));
+    }
+
+    #[test]
+    fn test_error_display_no_line() {
+        assert_eq!(ParseError::new("empty input").to_string(), "error: empty input\n --> <input>");
+    }
+}
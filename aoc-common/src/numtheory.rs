@@ -0,0 +1,176 @@
+use num_traits::Signed;
+
+/*
+Integer helpers, generic over the signed types: i64, i128 or BigInt when the values grow too big.
+Moduli should be positive.
+*/
+
+pub fn gcd<T: Signed + Clone>(a: &T, b: &T) -> T {
+    // always non-negative, gcd(0, 0) = 0
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        (a, b) = (b.clone(), a % b);
+    }
+    return a;
+}
+
+pub fn lcm<T: Signed + Clone>(a: &T, b: &T) -> T {
+    if a.is_zero() || b.is_zero() {
+        return T::zero();
+    }
+    return (a.clone() / gcd(a, b) * b.clone()).abs();
+}
+
+pub fn extended_gcd<T: Signed + Clone>(a: &T, b: &T) -> (T, T, T) {
+    // find gcd(a,b) and numbers s and t such that sa+tb = gcd(a,b)
+    // returns (gcd(a,b), s, t), the gcd being non-negative
+    let (mut oldr, mut r) = (a.clone(), b.clone());
+    let (mut olds, mut s) = (T::one(), T::zero());
+    let (mut oldt, mut t) = (T::zero(), T::one());
+
+    while !r.is_zero() {
+        let quotient = oldr.clone() / r.clone();
+        (oldr, r) = (r.clone(), oldr - quotient.clone()*r);
+        (olds, s) = (s.clone(), olds - quotient.clone()*s);
+        (oldt, t) = (t.clone(), oldt - quotient*t);
+    }
+
+    if oldr.is_negative() {
+        return (-oldr, -olds, -oldt);
+    }
+    return (oldr, olds, oldt);
+}
+
+pub fn positive_mod<T: Signed + Clone>(a: &T, n: &T) -> T {
+    // a mod n, in [0, n[
+    let r = a.clone() % n.clone();
+    if r.is_negative() {
+        return r + n.abs();
+    }
+    return r;
+}
+
+pub fn modular_inverse<T: Signed + Clone>(a: &T, n: &T) -> Option<T> {
+    // x in [0, n[ such that ax = 1 mod n, None when a and n are not coprime
+    let (g, x, _) = extended_gcd(&positive_mod(a, n), n);
+    if g.is_one() {
+        return Some(positive_mod(&x, n));
+    }
+    return None;
+}
+
+pub fn crt_pair<T: Signed + Clone>(c1: &(T, T), c2: &(T, T)) -> Option<(T, T)> {
+    /*
+    Solve x = a1 mod n1 and x = a2 mod n2, the moduli need not be coprime.
+    Returns (x, lcm(n1,n2)) with x in [0, lcm[, or None when the congruences contradict each other.
+
+    x = a1 + k*n1 = a2 mod n2
+    k*n1 = a2-a1 mod n2, solvable only when g = gcd(n1,n2) divides a2-a1
+    k*(n1/g) = (a2-a1)/g mod n2/g, where n1/g is invertible
+    */
+    let (a1, n1) = c1;
+    let (a2, n2) = c2;
+    let g = gcd(n1, n2);
+    let diff = a2.clone() - a1.clone();
+    if !(diff.clone() % g.clone()).is_zero() {
+        return None;
+    }
+
+    let small_n2 = n2.clone() / g.clone();
+    let inv = modular_inverse(&(n1.clone() / g.clone()), &small_n2)?;
+    let k = positive_mod(&(diff / g * inv), &small_n2);
+    let size = n1.clone() * small_n2;
+    let x = positive_mod(&(a1.clone() + k*n1.clone()), &size);
+    return Some((x, size));
+}
+
+pub fn crt<T: Signed + Clone>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    // all the (residue, modulus) pairs at once, x = 0 mod 1 for an empty list
+    let mut res = (T::zero(), T::one());
+    for c in congruences {
+        res = crt_pair(&res, c)?;
+    }
+    return Some(res);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use proptest::prelude::*;
+
+    fn brute_gcd(a: i64, b: i64) -> i64 {
+        (1..=a.abs().max(b.abs())).rev().find(|d| a % d == 0 && b % d == 0).unwrap_or(0)
+    }
+
+    fn brute_crt(congruences: &[(i64, i64)]) -> Option<i64> {
+        let size = congruences.iter().fold(1, |acc, (_, n)| lcm(&acc, n));
+        (0..size).find(|x| congruences.iter().all(|(a, n)| positive_mod(&(x - a), n) == 0))
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(&4i64, &6), 12);
+        assert_eq!(lcm(&-4i64, &6), 12);
+        assert_eq!(lcm(&0i64, &6), 0);
+    }
+
+    #[test]
+    fn test_crt_example() {
+        // the loops of aoc08's tests
+        assert_eq!(crt(&[(2i64, 3), (3, 5)]), Some((8, 15)));
+        assert_eq!(crt(&[(1i64, 6), (3, 4)]), Some((7, 12)));
+        assert_eq!(crt(&[(1i64, 6), (2, 4)]), None);
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_big() {
+        let n1 = BigInt::from(1_000_000_007i64) * BigInt::from(998_244_353i64);
+        let n2 = BigInt::from(1_000_000_009i64) * BigInt::from(998_244_353i64);
+        let (x, size) = crt(&[(BigInt::from(5), n1.clone()), (BigInt::from(5 + 998_244_353i64), n2.clone())]).unwrap();
+        assert_eq!(size, lcm(&n1, &n2));
+        assert_eq!(positive_mod(&(x.clone() - BigInt::from(5)), &n1), BigInt::from(0));
+        assert_eq!(positive_mod(&(x - BigInt::from(5 + 998_244_353i64)), &n2), BigInt::from(0));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_gcd_brute_force(a in -200i64..200, b in -200i64..200) {
+            prop_assert_eq!(gcd(&a, &b), brute_gcd(a, b));
+            prop_assert_eq!(gcd(&(a as i128), &(b as i128)), brute_gcd(a, b) as i128);
+            prop_assert_eq!(gcd(&BigInt::from(a), &BigInt::from(b)), BigInt::from(brute_gcd(a, b)));
+        }
+
+        #[test]
+        fn prop_extended_gcd_bezout(a in -10_000i64..10_000, b in -10_000i64..10_000) {
+            let (g, s, t) = extended_gcd(&a, &b);
+            prop_assert_eq!(g, gcd(&a, &b));
+            prop_assert_eq!(s*a + t*b, g);
+        }
+
+        #[test]
+        fn prop_modular_inverse(a in -100i64..100, n in 1i64..100) {
+            match modular_inverse(&a, &n) {
+                Some(x) => {
+                    prop_assert!(0 <= x && x < n);
+                    prop_assert_eq!(positive_mod(&(a*x), &n), 1 % n);
+                },
+                None => prop_assert!(gcd(&a, &n) != 1),
+            }
+        }
+
+        #[test]
+        fn prop_crt_brute_force(congruences in prop::collection::vec((-50i64..50, 1i64..20), 0..4)) {
+            let expected = brute_crt(&congruences);
+            prop_assert_eq!(crt(&congruences).map(|(x, _)| x), expected);
+
+            let wide: Vec<(i128, i128)> = congruences.iter().map(|(a, n)| (*a as i128, *n as i128)).collect();
+            prop_assert_eq!(crt(&wide).map(|(x, _)| x as i64), expected);
+
+            let big: Vec<(BigInt, BigInt)> = congruences.iter().map(|(a, n)| (BigInt::from(*a), BigInt::from(*n))).collect();
+            prop_assert_eq!(crt(&big).map(|(x, _)| x), expected.map(BigInt::from));
+        }
+    }
+}
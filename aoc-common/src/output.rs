@@ -0,0 +1,142 @@
+use std::process;
+use std::time::Instant;
+use crate::{ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json, // one record per line (NDJSON)
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    pub fn take_from_args(args: &mut Vec<String>) -> Format {
+        /*
+        Removes "--format <text|json>" from the arguments, so that the binaries can keep
+        reading their positional arguments as before.
+        */
+        let pos = match args.iter().position(|a| a == "--format") {
+            Some(pos) => pos,
+            None => return Format::Text,
+        };
+        let format = args.get(pos+1).and_then(|name| Format::from_name(name)).unwrap_or_else(|| {
+            eprintln!("error: --format expects `text` or `json`");
+            process::exit(2);
+        });
+        args.drain(pos..(pos+2));
+        return format;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub day: u32,
+    pub input: String, // the file the answer was computed from, "-" for stdin
+    pub part: u32,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+impl Record {
+    pub fn to_json(&self) -> String {
+        // written by hand to keep this crate free of dependencies, only the answer needs escaping
+        return format!(
+            "{{\"day\":{},\"input\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\"solve_ns\":{}}}",
+            self.day, json_string(&self.input), self.part, json_string(&self.answer), self.parse_ns, self.solve_ns
+        );
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            c if (c as u32) < 0x20 => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    return res;
+}
+
+pub fn solve_timed<S: Solution>(contents: &str, filename: &str, part: Option<u32>) -> Result<Vec<Record>, ParseError> {
+    // parse once, then solve the requested parts, timing every stage
+    let start = Instant::now();
+    let input = S::parse(contents)?;
+    let parse_ns = start.elapsed().as_nanos() as u64;
+
+    let mut records = vec![];
+    if part != Some(2) {
+        let start = Instant::now();
+        let answer = S::part1(&input).to_string();
+        records.push(Record{day: S::DAY, input: filename.to_string(), part: 1, answer, parse_ns, solve_ns: start.elapsed().as_nanos() as u64});
+    }
+    if part != Some(1) {
+        let start = Instant::now();
+        let answer = S::part2(&input).to_string();
+        records.push(Record{day: S::DAY, input: filename.to_string(), part: 2, answer, parse_ns, solve_ns: start.elapsed().as_nanos() as u64});
+    }
+    return Ok(records);
+}
+
+pub fn print_json<S: Solution>(contents: &str, filename: &str) -> bool {
+    // returns false when the input could not be parsed, the diagnostic is printed on stderr
+    match solve_timed::<S>(contents, filename, None) {
+        Ok(records) => {
+            for record in records {
+                println!("{}", record.to_json());
+            }
+            return true;
+        },
+        Err(e) => {
+            eprintln!("{}", e.with_file(filename));
+            return false;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
+    #[test]
+    fn test_take_format() {
+        let mut a = args(&["aoc05", "--format", "json", "input"]);
+        assert_eq!(Format::take_from_args(&mut a), Format::Json);
+        assert_eq!(a, args(&["aoc05", "input"]));
+    }
+
+    #[test]
+    fn test_take_format_default() {
+        let mut a = args(&["aoc05", "input"]);
+        assert_eq!(Format::take_from_args(&mut a), Format::Text);
+        assert_eq!(a.len(), 2);
+    }
+
+    #[test]
+    fn test_record_json() {
+        let r = Record{day: 8, input: "aoc08/input".to_string(), part: 2, answer: "9177460370549".to_string(), parse_ns: 10, solve_ns: 20};
+        assert_eq!(r.to_json(), r#"{"day":8,"input":"aoc08/input","part":2,"answer":"9177460370549","parse_ns":10,"solve_ns":20}"#);
+    }
+}
@@ -0,0 +1,182 @@
+use std::ops::Deref;
+use std::str::FromStr;
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line<'a> {
+    /*
+    A line of the input along with its 1-based number, so that errors can point at it.
+    Derefs to the text, so it can be split and matched like a plain &str.
+    */
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Deref for Line<'a> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, token: &str, message: &str) -> ParseError {
+        return ParseError::at(self.number, self.text, token, message);
+    }
+
+    pub fn error_line(&self, message: &str) -> ParseError {
+        return ParseError::at(self.number, self.text, self.text, message);
+    }
+
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        let token = token.trim();
+        return token.parse::<T>().map_err(|_| self.error(token, &format!("expected a number, found `{}`", token)));
+    }
+
+    pub fn intseq(&self, s: &str) -> Result<Vec<i64>, ParseError> {
+        // space separated integers, repeated spaces are allowed
+        return s.split(' ').filter(|x| x.len()>0).map(|x| self.number::<i64>(x)).collect();
+    }
+
+    pub fn check_chars(&self, allowed: &str) -> Result<(), ParseError> {
+        match self.text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            Some((pos, c)) => Err(self.error(&self.text[pos..(pos+c.len_utf8())], &format!("unexpected character `{}`", c))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn check_width(&self, width: usize) -> Result<(), ParseError> {
+        // for grids, every line should be as wide as the first one
+        let found = self.text.chars().count();
+        if found != width {
+            return Err(self.error_line(&format!("expected {} columns like the first line, found {}", width, found)));
+        }
+        return Ok(());
+    }
+}
+
+pub fn normalize(contents: &str) -> String {
+    /*
+    Makes inputs edited anywhere look the same: no byte order mark, '\n' line endings,
+    no trailing blanks on the lines and no blank lines at the end, only a final '\n'.
+    Lines keep their numbers, so that errors still point at the right place of the file.
+    */
+    let contents = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+    let mut res = String::with_capacity(contents.len());
+    for line in contents.split('\n') {
+        res += line.trim_end();
+        res.push('\n');
+    }
+    let len = res.trim_end().len();
+    res.truncate(len);
+    if len > 0 {
+        res.push('\n');
+    }
+    return res;
+}
+
+pub fn lines(contents: &str) -> impl Iterator<Item = Line<'_>> {
+    return contents.split('\n').enumerate().map(|(i, text)| Line{number: i+1, text});
+}
+
+pub fn grid_lines(contents: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    // the lines of an input holding a single grid: empty lines can only end it
+    let mut res: Vec<Line> = lines(contents).collect();
+    while res.last().is_some_and(|l| l.len() == 0) {
+        res.pop();
+    }
+    if let Some(empty) = res.iter().find(|l| l.len() == 0) {
+        return Err(empty.error_line("unexpected empty line, the grid should be in one piece"));
+    }
+    return Ok(res);
+}
+
+pub fn blocks(contents: &str) -> Vec<Vec<Line<'_>>> {
+    // groups of consecutive non-empty lines, separated by one or more empty lines
+    let mut blocks: Vec<Vec<Line>> = vec![];
+    let mut block: Vec<Line> = vec![];
+    for line in lines(contents) {
+        if line.trim().len() == 0 {
+            if block.len() > 0 {
+                blocks.push(block);
+                block = vec![];
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if block.len() > 0 {
+        blocks.push(block);
+    }
+    return blocks;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line{number: 1, text}
+    }
+
+    #[test]
+    fn test_parse_intseq_empty() {
+        assert_eq!(line("").intseq(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_intseq_spaces() {
+        assert_eq!(line("  7  15   30").intseq("  7  15   30"), Ok(vec![7, 15, 30]));
+    }
+
+    #[test]
+    fn test_parse_intseq_negative() {
+        assert_eq!(line("10 -3 0").intseq("10 -3 0"), Ok(vec![10, -3, 0]));
+    }
+
+    #[test]
+    fn test_parse_intseq_error() {
+        let l = line("seeds: 12 1x 4");
+        let e = l.intseq(&l[6..]).unwrap_err();
+        assert_eq!(e.column, 11);
+        assert_eq!(e.len, 2);
+        assert_eq!(e.message, "expected a number, found `1x`");
+    }
+
+    #[test]
+    fn test_check_chars() {
+        assert_eq!(line("#..#").check_chars(".#"), Ok(()));
+        let e = line("#.O#").check_chars(".#").unwrap_err();
+        assert_eq!((e.column, e.len), (3, 1));
+    }
+
+    #[test]
+    fn test_check_width() {
+        assert_eq!(line("#..#").check_width(4), Ok(()));
+        assert!(line("#..#").check_width(5).is_err());
+    }
+
+    #[test]
+    fn test_lines_numbers() {
+        let numbers: Vec<usize> = lines("a\nb\n").map(|l| l.number).collect();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("\u{feff}ab \r\ncd\r\n\r\n\tef\t\r\n\r\n  \n"), "ab\ncd\n\n\tef\n");
+        assert_eq!(normalize("ab"), "ab\n");
+        assert_eq!(normalize("\r\n\n"), "");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let b = blocks("a\nb\n\n\nc\n");
+        assert_eq!(b.len(), 2);
+        assert_eq!(b[0].iter().map(|l| l.text).collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!((b[1][0].number, b[1][0].text), (5, "c"));
+        assert_eq!(blocks("\n\n").len(), 0);
+    }
+}
@@ -0,0 +1,54 @@
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PipeType {
+    None = 0,
+    Horizontal = 1,
+    Vertical = 2,
+    BendUL = 3,
+    BendUR = 4,
+    BendDL = 5,
+    BendDR = 6,
+}
+
+impl PipeType {
+    pub fn from_char(c: char) -> PipeType {
+        match c {
+            '-' => PipeType::Horizontal,
+            '|' => PipeType::Vertical,
+            'J' => PipeType::BendUL,
+            'L' => PipeType::BendUR,
+            '7' => PipeType::BendDL,
+            'F' => PipeType::BendDR,
+            _ => PipeType::None,
+        }
+    }
+
+    pub fn to_char(&self) -> char {
+        match self {
+            PipeType::None => '.',
+            PipeType::Horizontal => '-',
+            PipeType::Vertical => '|',
+            PipeType::BendUL => 'J',
+            PipeType::BendUR => 'L',
+            PipeType::BendDL => '7',
+            PipeType::BendDR => 'F',
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipe_char_roundtrip() {
+        for c in ['.', '-', '|', 'J', 'L', '7', 'F'] {
+            assert_eq!(PipeType::from_char(c).to_char(), c);
+        }
+    }
+
+    #[test]
+    fn test_pipe_unknown_char() {
+        assert_eq!(PipeType::from_char('S'), PipeType::None);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+use std::ops::Range;
+use crate::Generator;
+use crate::rng::Rng;
+
+pub trait Reference: Generator {
+    /*
+    Naive solutions, slow but plainly right, to check a day's shortcuts against.
+    Days implement it behind their `reference` feature.
+    */
+    fn reference_part1(input: &Self::Input) -> Self::Answer1;
+    fn reference_part2(input: &Self::Input) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub seed: u64,
+    pub size: usize,
+    pub part: u32,
+    pub answer: String,
+    pub expected: String,
+    pub input: String,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "part {} gives {} but the reference gives {}", self.part, self.answer, self.expected)?;
+        writeln!(f, "on the input of --seed {} --size {}:", self.seed, self.size)?;
+        write!(f, "{}", self.input)
+    }
+}
+
+pub fn first_divergence<S: Reference>(seeds: Range<u64>, size: usize) -> Option<Divergence> {
+    /*
+    Runs the solver and the reference on the generated input of each seed,
+    returns where they first give different answers.
+    */
+    return first_divergence_with::<S>(seeds, size, S::generate);
+}
+
+pub fn first_divergence_with<S: Reference>(seeds: Range<u64>, size: usize, generate: fn(&mut Rng, usize) -> String) -> Option<Divergence> {
+    // for the days whose generated inputs are too big for the reference, with a generator of smaller ones
+    for seed in seeds {
+        let input = generate(&mut Rng::new(seed), size);
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => panic!("generated input of seed {} does not parse:\n{}", seed, e),
+        };
+
+        let answers = [
+            (S::part1(&parsed).to_string(), S::reference_part1(&parsed).to_string()),
+            (S::part2(&parsed).to_string(), S::reference_part2(&parsed).to_string()),
+        ];
+        for (part, (answer, expected)) in answers.into_iter().enumerate() {
+            if answer != expected {
+                return Some(Divergence{seed, size, part: part as u32 + 1, answer, expected, input});
+            }
+        }
+    }
+    return None;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Solution};
+
+    // sums the numbers of the input, part 2 wrongly ignores the ones above 5
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(contents: &str) -> Result<Vec<u64>, ParseError> {
+            Ok(contents.split_whitespace().map(|s| s.parse().unwrap()).collect())
+        }
+        fn part1(input: &Vec<u64>) -> u64 {
+            input.iter().sum()
+        }
+        fn part2(input: &Vec<u64>) -> u64 {
+            input.iter().filter(|x| **x <= 5).sum()
+        }
+    }
+
+    impl Generator for Sum {
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| rng.range(0, 6).to_string() + "\n").collect()
+        }
+    }
+
+    impl Reference for Sum {
+        fn reference_part1(input: &Vec<u64>) -> u64 {
+            let mut res = 0;
+            for x in input {
+                res += x;
+            }
+            return res;
+        }
+        fn reference_part2(input: &Vec<u64>) -> u64 {
+            Sum::reference_part1(input)
+        }
+    }
+
+    #[test]
+    fn test_first_divergence() {
+        let divergence = first_divergence::<Sum>(0..100, 3).unwrap();
+        assert_eq!(divergence.part, 2);
+        // the earlier seeds had no 6
+        for seed in 0..divergence.seed {
+            assert!(!Sum::generate(&mut Rng::new(seed), 3).contains('6'));
+        }
+        assert!(divergence.to_string().contains(&format!("--seed {} --size 3", divergence.seed)));
+        assert_eq!(first_divergence::<Sum>(0..0, 3), None);
+    }
+}
@@ -0,0 +1,363 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::Solution;
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/*
+Pictures of a puzzle state, written as PNG, PPM or SVG images without any dependency.
+*/
+
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Picture;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    pub const WHITE: Color = Color::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color{r, g, b}
+    }
+
+    pub fn gray(level: u8) -> Color {
+        Color::new(level, level, level)
+    }
+
+    pub fn mix(self, other: Color, ratio: f64) -> Color {
+        // ratio 0 gives self, 1 gives other
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64)*ratio).round() as u8;
+        Color::new(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b))
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picture {
+    /*
+    A grid of colored cells, with paths drawn over them from cell center to cell center.
+    Points are (row, column) like everywhere else.
+    */
+    pub background: Color,
+    pub cells: Grid<Color>,
+    pub paths: Vec<(Vec<Point>, Color)>,
+}
+
+impl Picture {
+    pub fn new(height: usize, width: usize, background: Color) -> Picture {
+        Picture{background, cells: Grid::new(height, width, background), paths: vec![]}
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, background: Color, to_color: impl Fn(&T) -> Color) -> Picture {
+        Picture{background, cells: grid.map(to_color), paths: vec![]}
+    }
+
+    pub fn add_path(&mut self, points: Vec<Point>, color: Color) {
+        self.paths.push( (points, color) );
+    }
+
+    pub fn fill_polygon(&mut self, corners: &Vec<Point>, color: Color) {
+        /*
+        Colors the cells whose center is inside the polygon, scanning the rows:
+        the edges crossing a row pair up around the inside spans.
+        Cells right on the border may go either way, draw it over afterwards.
+        */
+        for row in 0..self.cells.height() {
+            let r = row as f64;
+            let mut crossings: Vec<f64> = vec![];
+            for (i, a) in corners.iter().enumerate() {
+                let b = corners[(i+1) % corners.len()];
+                if (a.row as f64 > r) != (b.row as f64 > r) {
+                    crossings.push(a.col as f64 + (r - a.row as f64)*(b.col - a.col) as f64/(b.row - a.row) as f64);
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+            for span in crossings.chunks(2) {
+                if span.len() < 2 {
+                    continue;
+                }
+                let first = span[0].floor().max(-1.0) as i64 + 1;
+                let last = (span[1].ceil() as i64 - 1).min(self.cells.width() as i64 - 1);
+                for col in first..=last {
+                    self.cells[(row, col as usize)] = color;
+                }
+            }
+        }
+    }
+
+    pub fn flatten(&self) -> Grid<Color> {
+        // the paths painted over the cells they go through, one color per cell
+        let mut res = self.cells.clone();
+        for (points, color) in &self.paths {
+            for (i, point) in points.iter().enumerate() {
+                let mut current = *point;
+                let next = *points.get(i+1).unwrap_or(point);
+                loop {
+                    if current.row >= 0 && current.col >= 0 {
+                        if let Some(cell) = res.get_mut(current.row as usize, current.col as usize) {
+                            *cell = *color;
+                        }
+                    }
+                    if current == next {
+                        break;
+                    }
+                    current = Point::new(current.row + (next.row - current.row).signum(), current.col + (next.col - current.col).signum());
+                }
+            }
+        }
+        return res;
+    }
+
+    pub fn default_scale(&self) -> usize {
+        // pixels per cell, for images about 800 pixels wide
+        let side = self.cells.height().max(self.cells.width()).max(1);
+        return (800 / side).clamp(1, 20);
+    }
+
+    fn pixels(&self, scale: usize) -> (usize, usize, Vec<Color>) {
+        let (height, width) = (self.cells.height()*scale, self.cells.width()*scale);
+        let mut pixels = vec![self.background; height*width];
+        for ((row, col), color) in self.cells.iter() {
+            for i in 0..scale {
+                let start = (row*scale + i)*width + col*scale;
+                pixels[start..(start+scale)].fill(*color);
+            }
+        }
+
+        // lines from center to center, a third of a cell thick
+        let thickness = (scale/3).max(1) as i64;
+        let center = |p: &Point| (p.row*scale as i64 + scale as i64/2, p.col*scale as i64 + scale as i64/2);
+        for (points, color) in &self.paths {
+            for pair in points.windows(2) {
+                let (from, to) = (center(&pair[0]), center(&pair[1]));
+                let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).max(1);
+                for k in 0..=steps {
+                    let x = from.0 + (to.0 - from.0)*k/steps - thickness/2;
+                    let y = from.1 + (to.1 - from.1)*k/steps - thickness/2;
+                    for px in x.max(0)..(x + thickness).min(height as i64) {
+                        for py in y.max(0)..(y + thickness).min(width as i64) {
+                            pixels[px as usize*width + py as usize] = *color;
+                        }
+                    }
+                }
+            }
+        }
+        return (height, width, pixels);
+    }
+
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let (height, width, pixels) = self.pixels(scale);
+        let mut res = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        for p in pixels {
+            res.extend([p.r, p.g, p.b]);
+        }
+        return res;
+    }
+
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        /*
+        RGB, 8 bits per channel. The pixels are stored in uncompressed deflate blocks:
+        the files are big, but it takes no compressor.
+        */
+        let (height, width, pixels) = self.pixels(scale);
+        let mut raw: Vec<u8> = Vec::with_capacity(height*(3*width + 1));
+        for row in pixels.chunks(width.max(1)).take(height) {
+            raw.push(0); // no filter
+            for p in row {
+                raw.extend([p.r, p.g, p.b]);
+            }
+        }
+
+        let mut zlib: Vec<u8> = vec![0x78, 0x01];
+        let blocks: Vec<&[u8]> = if raw.is_empty() { vec![&[]] } else { raw.chunks(0xffff).collect() };
+        for (i, block) in blocks.iter().enumerate() {
+            zlib.push((i + 1 == blocks.len()) as u8);
+            zlib.extend((block.len() as u16).to_le_bytes());
+            zlib.extend((!(block.len() as u16)).to_le_bytes());
+            zlib.extend_from_slice(block);
+        }
+        zlib.extend(adler32(&raw).to_be_bytes());
+
+        let mut header: Vec<u8> = vec![];
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut res: Vec<u8> = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        png_chunk(&mut res, b"IHDR", &header);
+        png_chunk(&mut res, b"IDAT", &zlib);
+        png_chunk(&mut res, b"IEND", &[]);
+        return res;
+    }
+
+    pub fn to_svg(&self, scale: usize) -> String {
+        // runs of same-colored cells become a single rectangle, the background one for all
+        let (height, width) = (self.cells.height()*scale, self.cells.width()*scale);
+        let mut res = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n", width, height, width, height);
+        res += &format!("<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>\n", width, height, self.background.hex());
+        for (row, cells) in self.cells.rows().enumerate() {
+            let mut col = 0;
+            while col < cells.len() {
+                let run = cells[col..].iter().take_while(|c| **c == cells[col]).count();
+                if cells[col] != self.background {
+                    res += &format!("<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n", col*scale, row*scale, run*scale, scale, cells[col].hex());
+                }
+                col += run;
+            }
+        }
+        for (points, color) in &self.paths {
+            let coords: Vec<String> = points.iter()
+                .map(|p| format!("{},{}", p.col as f64*scale as f64 + scale as f64/2.0, p.row as f64*scale as f64 + scale as f64/2.0))
+                .collect();
+            res += &format!("<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"{}\" stroke-linejoin=\"round\"/>\n", coords.join(" "), color.hex(), (scale as f64/3.0).max(1.0));
+        }
+        res += "</svg>\n";
+        return res;
+    }
+
+    pub fn save(&self, path: &str) -> io::Result<()> {
+        // the format is picked from the extension
+        let scale = self.default_scale();
+        let contents = match Path::new(path).extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(scale),
+            Some("ppm") => self.to_ppm(scale),
+            Some("svg") => self.to_svg(scale).into_bytes(),
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "unknown image format, expected a .png, .ppm or .svg file")),
+        };
+        return fs::write(path, contents);
+    }
+}
+
+fn png_chunk(res: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    res.extend((data.len() as u32).to_be_bytes());
+    let start = res.len();
+    res.extend_from_slice(kind);
+    res.extend_from_slice(data);
+    let crc = crc32(&res[start..]);
+    res.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xffffffff;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    return !crc;
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    return (b << 16) | a;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Color = Color::new(255, 0, 0);
+
+    fn small_picture() -> Picture {
+        let mut picture = Picture::new(2, 3, Color::WHITE);
+        picture.cells[(0, 1)] = RED;
+        picture.add_path(vec![Point::new(1, 0), Point::new(1, 2)], Color::BLACK);
+        return picture;
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let ppm = small_picture().to_ppm(3);
+        let header = b"P6\n9 6\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 9*6*3);
+        // pixel (1, 4) is in the red cell, the path runs on the row 4
+        let pixel = |x: usize, y: usize| &ppm[header.len() + 3*(x*9 + y)..][..3];
+        assert_eq!(pixel(1, 4), [255, 0, 0]);
+        assert_eq!(pixel(1, 7), [255, 255, 255]);
+        assert_eq!(pixel(4, 0), [255, 255, 255]);
+        assert_eq!(pixel(4, 1), [0, 0, 0]);
+        assert_eq!(pixel(4, 7), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_png() {
+        let png = small_picture().to_png(3);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 9, 0, 0, 0, 6]);
+        assert_eq!(&png[png.len()-12..], [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]);
+        // a single stored block of the 6 rows of 1 + 9*3 bytes
+        let idat = 8 + 25;
+        assert_eq!(&png[idat+4..idat+8], b"IDAT");
+        assert_eq!(u32::from_be_bytes(png[idat..idat+4].try_into().unwrap()), 2 + 5 + 6*28 + 4);
+    }
+
+    #[test]
+    fn test_svg() {
+        let svg = small_picture().to_svg(10);
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\""));
+        assert!(svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#ff0000\"/>"));
+        assert!(svg.contains("points=\"5,15 25,15\""));
+        assert_eq!(svg.matches("<rect").count(), 2);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut picture = small_picture();
+        picture.add_path(vec![Point::new(0, 2), Point::new(-1, 2)], RED);
+        let cells = picture.flatten();
+        assert_eq!(cells.row(0), [Color::WHITE, RED, RED]);
+        assert_eq!(cells.row(1), [Color::BLACK; 3]);
+    }
+
+    #[test]
+    fn test_fill_polygon() {
+        // a U shape, its hollow stays empty, the top side of the border is counted in
+        let corners = vec![
+            Point::new(0, 0), Point::new(0, 2), Point::new(3, 2), Point::new(3, 4),
+            Point::new(0, 4), Point::new(0, 6), Point::new(5, 6), Point::new(5, 0),
+        ];
+        let mut picture = Picture::new(6, 7, Color::WHITE);
+        picture.fill_polygon(&corners, RED);
+        let inside: Vec<String> = picture.cells.rows().map(|row| row.iter().map(|c| if *c == RED { '#' } else { '.' }).collect()).collect();
+        assert_eq!(inside, vec![
+            ".#...#.",
+            ".#...#.",
+            ".#...#.",
+            ".#####.",
+            ".#####.",
+            ".......",
+        ]);
+    }
+
+    #[test]
+    fn test_save_unknown_format() {
+        assert_eq!(small_picture().save("/nonexistent/picture.gif").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+}
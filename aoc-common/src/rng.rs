@@ -0,0 +1,90 @@
+/*
+A small seeded random generator (SplitMix64), enough for the input generators:
+the same seed always gives the same sequence, on every platform.
+*/
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng{state: seed}
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        // in [0, n[, n must not be 0
+        assert!(n > 0, "empty range");
+        return ((self.next_u64() as u128 * n as u128) >> 64) as usize;
+    }
+
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        // in [low, high], both included
+        assert!(low <= high, "empty range");
+        let span = (high as i128 - low as i128 + 1) as u128;
+        return (low as i128 + ((self.next_u64() as u128 * span) >> 64) as i128) as i64;
+    }
+
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / ((1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // Fisher-Yates
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i+1));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed() {
+        let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(7);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let x = rng.range(-3, 3);
+            assert!((-3..=3).contains(&x));
+            seen[(x+3) as usize] = true;
+            assert!(rng.below(5) < 5);
+        }
+        assert!(seen.iter().all(|s| *s));
+        assert_eq!(rng.range(i64::MIN, i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(3);
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<u32>>());
+        items.sort();
+        assert_eq!(items, (0..50).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+use num_traits::Zero;
+
+/*
+Shortest paths over any state type: the graph is only known through a successors callback,
+giving the next states with the cost to reach them. Costs must not be negative.
+*/
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    // from one of the starts to the goal, both included
+    pub states: Vec<S>,
+}
+
+struct SearchHead<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for SearchHead<S, C> {
+    // reversed so that the heap pops the lowest estimate first, then the furthest along
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<S, C: Ord> PartialOrd for SearchHead<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for SearchHead<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, C: Ord> Eq for SearchHead<S, C> {}
+
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    /*
+    The heuristic must never overestimate the remaining cost to a goal, a zero one being Dijkstra.
+    Returns the cheapest path to the first goal found, None when no goal can be reached.
+    */
+    let mut best: HashMap<S, (C, Option<S>)> = HashMap::new(); // state -> (cost, previous state)
+    let mut heads = BinaryHeap::new();
+    for start in starts {
+        best.insert(start.clone(), (C::zero(), None));
+        heads.push(SearchHead{estimate: heuristic(&start), cost: C::zero(), state: start});
+    }
+
+    while let Some(current) = heads.pop() {
+        if current.cost > best[&current.state].0 {
+            // a cheaper way to this state was found after it was queued
+            continue;
+        }
+
+        if is_goal(&current.state) {
+            return Some(Path{cost: current.cost, states: rebuild_path(&best, current.state)});
+        }
+
+        for (next, step_cost) in successors(&current.state) {
+            let new_cost = current.cost + step_cost;
+            if let Some((known_cost, _)) = best.get(&next) {
+                if *known_cost <= new_cost {
+                    continue;
+                }
+            }
+            best.insert(next.clone(), (new_cost, Some(current.state.clone())));
+            heads.push(SearchHead{estimate: new_cost + heuristic(&next), cost: new_cost, state: next});
+        }
+    }
+
+    return None;
+}
+
+fn rebuild_path<S: Clone + Eq + Hash, C>(best: &HashMap<S, (C, Option<S>)>, goal: S) -> Vec<S> {
+    // follow the previous states back to a start
+    let mut states = vec![goal];
+    while let Some(prev) = &best[states.last().unwrap()].1 {
+        states.push(prev.clone());
+    }
+    states.reverse();
+    return states;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn floyd(n: usize, edges: &Vec<(usize, usize, u32)>) -> Vec<Vec<Option<u32>>> {
+        let mut dist = vec![vec![None; n]; n];
+        for (i, row) in dist.iter_mut().enumerate() {
+            row[i] = Some(0);
+        }
+        for (a, b, c) in edges {
+            dist[*a][*b] = Some(dist[*a][*b].map_or(*c, |d: u32| d.min(*c)));
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    if let (Some(a), Some(b)) = (dist[i][k], dist[k][j]) {
+                        dist[i][j] = Some(dist[i][j].map_or(a + b, |d| d.min(a + b)));
+                    }
+                }
+            }
+        }
+        return dist;
+    }
+
+    #[test]
+    fn test_grid_path() {
+        // a 5x5 open grid, each step costs 1, the heuristic is the manhattan distance
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x+1, y), (x-1, y), (x, y+1), (x, y-1)].into_iter()
+                .filter(|(x, y)| (0..5).contains(x) && (0..5).contains(y))
+                .map(|p| (p, 1))
+        };
+        let path = astar([(0, 0)], successors, |&(x, y)| (4-x) + (4-y), |p| *p == (4, 4)).unwrap();
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.states.len(), 9);
+        assert_eq!((path.states[0], path.states[8]), ((0, 0), (4, 4)));
+        assert_eq!(dijkstra([(0, 0)], successors, |p| *p == (4, 4)).unwrap().cost, 8);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let path: Option<Path<i32, i32>> = dijkstra([0], |x| if *x < 3 { vec![(x+1, 1)] } else { vec![] }, |x| *x == 5);
+        assert_eq!(path, None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_dijkstra_shortest(edges in prop::collection::vec((0usize..8, 0usize..8, 0u32..10), 0..25), start in 0usize..8, goal in 0usize..8) {
+            let successors = |x: &usize| edges.iter().filter(|(a, _, _)| a == x).map(|(_, b, c)| (*b, *c)).collect::<Vec<_>>();
+            let path = dijkstra([start], successors, |x| *x == goal);
+            prop_assert_eq!(path.as_ref().map(|p| p.cost), floyd(8, &edges)[start][goal]);
+
+            if let Some(path) = path {
+                // the path is made of edges adding up to the cost
+                prop_assert_eq!((path.states[0], *path.states.last().unwrap()), (start, goal));
+                let mut total = 0;
+                for w in path.states.windows(2) {
+                    total += edges.iter().filter(|(a, b, _)| *a == w[0] && *b == w[1]).map(|(_, _, c)| *c).min().unwrap();
+                }
+                prop_assert_eq!(total, path.cost);
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+pub fn workspace_root() -> PathBuf {
+    // the registry and the inputs it names live at the root, wherever the runner is started from
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    return manifest_dir.parent().unwrap_or(manifest_dir).to_path_buf();
+}
+
+pub fn answers_path() -> PathBuf {
+    return workspace_root().join(ANSWERS_FILE);
+}
+
+pub fn input_key(root: &Path, filename: &str) -> String {
+    /*
+    The name of an input in the registry: its path relative to the root, with `/` separators,
+    so that `./aoc05/input` or an absolute path find the entry of `aoc05/input`.
+    Inputs outside of the root keep their full path, unreadable ones their name.
+    */
+    let (path, root) = match (fs::canonicalize(filename), fs::canonicalize(root)) {
+        (Ok(path), Ok(root)) => (path, root),
+        _ => return filename.to_string(),
+    };
+    return match path.strip_prefix(&root) {
+        Ok(relative) => relative.iter().map(|part| part.to_string_lossy()).collect::<Vec<_>>().join("/"),
+        Err(_) => path.to_string_lossy().to_string(),
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u32,
+    pub input: String, // path of the input, relative to the repository root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(default, rename = "answer")]
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Correct,
+    Wrong(String), // the expected answer
+    Missing,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        // a missing file is an empty registry, so that the first --record can create it
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        return toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e));
+    }
+
+    pub fn save(&mut self, path: &Path) -> io::Result<()> {
+        self.entries.sort_by(|a, b| (a.day, &a.input).cmp(&(b.day, &b.input)));
+        let text = toml::to_string(self).expect("Could not serialize answers");
+        return fs::write(path, text);
+    }
+
+    fn entry(&self, day: u32, input: &str) -> Option<&Entry> {
+        return self.entries.iter().find(|e| e.day == day && e.input == input);
+    }
+
+    pub fn get(&self, day: u32, input: &str, part: u32) -> Option<&str> {
+        let entry = self.entry(day, input)?;
+        let answer = if part == 1 { &entry.part1 } else { &entry.part2 };
+        return answer.as_deref();
+    }
+
+    pub fn set(&mut self, day: u32, input: &str, part: u32, answer: &str) {
+        let pos = match self.entries.iter().position(|e| e.day == day && e.input == input) {
+            Some(pos) => pos,
+            None => {
+                self.entries.push(Entry{day, input: input.to_string(), part1: None, part2: None});
+                self.entries.len() - 1
+            }
+        };
+        let entry = &mut self.entries[pos];
+        if part == 1 {
+            entry.part1 = Some(answer.to_string());
+        } else {
+            entry.part2 = Some(answer.to_string());
+        }
+    }
+
+    pub fn check(&self, day: u32, input: &str, part: u32, answer: &str) -> Check {
+        return match self.get(day, input, part) {
+            None => Check::Missing,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Wrong(expected.to_string()),
+        };
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::get_day;
+    use aoc_common::input::read_input;
+
+    #[test]
+    fn set_and_check() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.check(3, "aoc03/input", 1, "12"), Check::Missing);
+        answers.set(3, "aoc03/input", 1, "12");
+        assert_eq!(answers.check(3, "aoc03/input", 1, "12"), Check::Correct);
+        assert_eq!(answers.check(3, "aoc03/input", 1, "13"), Check::Wrong("12".to_string()));
+        assert_eq!(answers.check(3, "aoc03/input", 2, "12"), Check::Missing);
+        assert_eq!(answers.check(3, "other", 1, "12"), Check::Missing);
+        answers.set(3, "aoc03/input", 2, "5");
+        assert_eq!(answers.entries.len(), 1);
+    }
+
+    #[test]
+    fn input_keys() {
+        // tests run from the aoc folder
+        let root = workspace_root();
+        assert_eq!(input_key(&root, "../aoc05/input"), "aoc05/input");
+        assert_eq!(input_key(&root, "./../aoc05/../aoc05/input"), "aoc05/input");
+        assert_eq!(input_key(&root, root.join("aoc05/input").to_str().unwrap()), "aoc05/input");
+        assert_eq!(input_key(&root, "no/such/input"), "no/such/input");
+    }
+
+    #[test]
+    fn toml_roundtrip() {
+        let text = "[[answer]]\nday = 14\ninput = \"aoc14/input\"\npart1 = \"109939\"\n";
+        let answers: Answers = toml::from_str(text).unwrap();
+        assert_eq!(answers.get(14, "aoc14/input", 1), Some("109939"));
+        assert_eq!(answers.get(14, "aoc14/input", 2), None);
+        assert_eq!(toml::to_string(&answers).unwrap(), text);
+    }
+
+    #[test]
+    fn recorded_answers_still_hold() {
+        // every answer of the checked-in registry, recomputed from its input
+        let root = workspace_root();
+        let answers = Answers::load(&answers_path()).unwrap();
+
+        for entry in &answers.entries {
+            let contents = read_input(root.join(&entry.input).to_str().unwrap()).unwrap();
+            let computed = get_day(entry.day).run(&contents, &entry.input, None).unwrap();
+            for record in computed {
+                let check = answers.check(entry.day, &entry.input, record.part, &record.answer);
+                assert!(!matches!(check, Check::Wrong(_)),
+                        "day {} part {} on {}: got {}, {:?}", entry.day, record.part, entry.input, record.answer, check);
+            }
+        }
+    }
+}
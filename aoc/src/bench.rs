@@ -0,0 +1,139 @@
+use std::hint::black_box;
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct StageTimings {
+    // each vector holds one duration per run, in nanoseconds
+    pub parse: Vec<u64>,
+    pub part1: Option<Vec<u64>>,
+    pub part2: Option<Vec<u64>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StageResult {
+    pub day: u32,
+    pub input: String, // as named in the answers registry
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub runs: usize,
+    pub results: Vec<StageResult>,
+}
+
+pub fn measure<R>(runs: usize, mut f: impl FnMut() -> R) -> Vec<u64> {
+    // one untimed call first, to warm up caches and allocator
+    black_box(f());
+
+    let mut samples = vec![];
+    for _ in 0..runs {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed().as_nanos() as u64);
+    }
+    return samples;
+}
+
+pub fn compute_stats(samples: &Vec<u64>) -> Stats {
+    let mut sorted = samples.clone();
+    sorted.sort();
+
+    let n = sorted.len();
+    let median = if n % 2 == 1 {
+        sorted[n/2]
+    } else {
+        (sorted[n/2 - 1] + sorted[n/2]) / 2
+    };
+    let mean = sorted.iter().sum::<u64>() as f64 / n as f64;
+    let variance = sorted.iter().map(|&s| (s as f64 - mean).powi(2)).sum::<f64>() / n as f64;
+
+    return Stats{
+        min_ns: sorted[0],
+        median_ns: median,
+        mean_ns: mean,
+        stddev_ns: variance.sqrt(),
+    };
+}
+
+pub fn stage_results(day: u32, input: &str, timings: &StageTimings) -> Vec<StageResult> {
+    let result = |stage: &str, samples: &Vec<u64>| StageResult{day, input: input.to_string(), stage: stage.to_string(), stats: compute_stats(samples)};
+    let mut res = vec![result("parse", &timings.parse)];
+    if let Some(samples) = &timings.part1 {
+        res.push(result("part1", samples));
+    }
+    if let Some(samples) = &timings.part2 {
+        res.push(result("part2", samples));
+    }
+    return res;
+}
+
+pub fn format_ns(ns: f64) -> String {
+    if ns < 1e3 {
+        return format!("{:.0}ns", ns);
+    } else if ns < 1e6 {
+        return format!("{:.2}µs", ns / 1e3);
+    } else if ns < 1e9 {
+        return format!("{:.2}ms", ns / 1e6);
+    }
+    return format!("{:.2}s", ns / 1e9);
+}
+
+pub fn print_result(result: &StageResult) {
+    let s = &result.stats;
+    println!(
+        "[{:02}] {:<5}  min {:>10}  median {:>10}  mean {:>10}  stddev {:>10}",
+        result.day,
+        result.stage,
+        format_ns(s.min_ns as f64),
+        format_ns(s.median_ns as f64),
+        format_ns(s.mean_ns),
+        format_ns(s.stddev_ns),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_odd() {
+        let s = compute_stats(&vec![5, 1, 3]);
+        assert_eq!(s.min_ns, 1);
+        assert_eq!(s.median_ns, 3);
+        assert_eq!(s.mean_ns, 3.0);
+        assert!((s.stddev_ns - (8.0f64/3.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_even() {
+        let s = compute_stats(&vec![4, 2, 2, 8]);
+        assert_eq!(s.min_ns, 2);
+        assert_eq!(s.median_ns, 3);
+        assert_eq!(s.mean_ns, 4.0);
+    }
+
+    #[test]
+    fn measure_count() {
+        assert_eq!(measure(7, || 1+1).len(), 7);
+    }
+
+    #[test]
+    fn units() {
+        assert_eq!(format_ns(12.0), "12ns");
+        assert_eq!(format_ns(1500.0), "1.50µs");
+        assert_eq!(format_ns(2_500_000.0), "2.50ms");
+        assert_eq!(format_ns(3e9), "3.00s");
+    }
+}
@@ -0,0 +1,117 @@
+use std::marker::PhantomData;
+use aoc_common::{Generator, ParseError, Solution};
+use aoc_common::animate::{Animate, Player};
+use aoc_common::output::{solve_timed, Record};
+use aoc_common::render::{Picture, Render};
+use aoc_common::rng::Rng;
+use crate::bench::{measure, StageTimings};
+
+pub const DAY_COUNT: u32 = 22;
+
+pub trait Day {
+    /*
+    Type-erased view of a Solution, so that the runner can pick a day at runtime.
+    */
+    fn run(&self, contents: &str, filename: &str, part: Option<u32>) -> Result<Vec<Record>, ParseError>;
+    fn bench(&self, contents: &str, part: Option<u32>, runs: usize) -> Result<StageTimings, ParseError>;
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+struct DayOf<S: Solution>(PhantomData<S>);
+
+impl<S: Generator> Day for DayOf<S> {
+    fn run(&self, contents: &str, filename: &str, part: Option<u32>) -> Result<Vec<Record>, ParseError> {
+        return solve_timed::<S>(contents, filename, part);
+    }
+
+    fn bench(&self, contents: &str, part: Option<u32>, runs: usize) -> Result<StageTimings, ParseError> {
+        // parse once outside of the timings so that a bad input is reported before measuring
+        let input = S::parse(contents)?;
+
+        let mut timings = StageTimings{parse: measure(runs, || S::parse(contents)), part1: None, part2: None};
+        if part != Some(2) {
+            timings.part1 = Some(measure(runs, || S::part1(&input)));
+        }
+        if part != Some(1) {
+            timings.part2 = Some(measure(runs, || S::part2(&input)));
+        }
+        return Ok(timings);
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        return S::generate(rng, size);
+    }
+}
+
+pub trait Renderer {
+    // only the days with something worth drawing have one
+    fn render(&self, contents: &str) -> Result<Picture, ParseError>;
+}
+
+impl<S: Render> Renderer for DayOf<S> {
+    fn render(&self, contents: &str) -> Result<Picture, ParseError> {
+        return Ok(S::render(&S::parse(contents)?));
+    }
+}
+
+pub trait Animator {
+    fn animate(&self, contents: &str, player: &mut Player) -> Result<(), ParseError>;
+}
+
+impl<S: Animate> Animator for DayOf<S> {
+    fn animate(&self, contents: &str, player: &mut Player) -> Result<(), ParseError> {
+        S::animate(&S::parse(contents)?, &mut |picture, caption| player.show(picture, caption));
+        return Ok(());
+    }
+}
+
+fn boxed<S: Generator + 'static>() -> Box<dyn Day> {
+    Box::new(DayOf::<S>(PhantomData))
+}
+
+pub fn get_day(day: u32) -> Box<dyn Day> {
+    match day {
+        1 => boxed::<aoc01::Day01>(),
+        2 => boxed::<aoc02::Day02>(),
+        3 => boxed::<aoc03::Day03>(),
+        4 => boxed::<aoc04::Day04>(),
+        5 => boxed::<aoc05::Day05>(),
+        6 => boxed::<aoc06::Day06>(),
+        7 => boxed::<aoc07::Day07>(),
+        8 => boxed::<aoc08::Day08>(),
+        9 => boxed::<aoc09::Day09>(),
+        10 => boxed::<aoc10::Day10>(),
+        11 => boxed::<aoc11::Day11>(),
+        12 => boxed::<aoc12::Day12>(),
+        13 => boxed::<aoc13::Day13>(),
+        14 => boxed::<aoc14::Day14>(),
+        15 => boxed::<aoc15::Day15>(),
+        16 => boxed::<aoc16::Day16>(),
+        17 => boxed::<aoc17::Day17>(),
+        18 => boxed::<aoc18::Day18>(),
+        19 => boxed::<aoc19::Day19>(),
+        20 => boxed::<aoc20::Day20>(),
+        21 => boxed::<aoc21::Day21>(),
+        22 => boxed::<aoc22::Day22>(),
+        _ => panic!("No solution for day {day}"),
+    }
+}
+
+pub fn get_renderer(day: u32) -> Option<Box<dyn Renderer>> {
+    match day {
+        10 => Some(Box::new(DayOf::<aoc10::Day10>(PhantomData))),
+        16 => Some(Box::new(DayOf::<aoc16::Day16>(PhantomData))),
+        17 => Some(Box::new(DayOf::<aoc17::Day17>(PhantomData))),
+        18 => Some(Box::new(DayOf::<aoc18::Day18>(PhantomData))),
+        _ => None,
+    }
+}
+
+pub fn get_animator(day: u32) -> Option<Box<dyn Animator>> {
+    match day {
+        14 => Some(Box::new(DayOf::<aoc14::Day14>(PhantomData))),
+        16 => Some(Box::new(DayOf::<aoc16::Day16>(PhantomData))),
+        17 => Some(Box::new(DayOf::<aoc17::Day17>(PhantomData))),
+        _ => None,
+    }
+}
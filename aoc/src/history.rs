@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::bench::{StageResult, Stats};
+
+pub const DEFAULT_HISTORY: &str = "bench-history.jsonl";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub commit: String,
+    pub timestamp: u64,
+    pub runs: usize,
+    pub day: u32,
+    pub input: String, // as named in the answers registry
+    pub stage: String,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+// the timings of a day's stage on an input
+pub type StageKey = (u32, String, String);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u32,
+    pub input: String,
+    pub stage: String,
+    pub base_ns: u64,
+    pub new_ns: u64,
+    pub change: f64, // relative change of the median, 0.1 is 10% slower
+    pub regressed: bool,
+}
+
+fn git_output(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    return Some(String::from_utf8_lossy(&output.stdout).trim().to_string());
+}
+
+pub fn current_commit() -> String {
+    /*
+    Short hash of HEAD, with a "-dirty" suffix when tracked files were modified,
+    so that timings of uncommitted code are not mixed with the commit's own.
+    */
+    let commit = match git_output(&["rev-parse", "--short", "HEAD"]) {
+        Some(c) => c,
+        None => return "unknown".to_string(),
+    };
+    let status = git_output(&["status", "--porcelain", "--untracked-files=no"]).unwrap_or_default();
+    if status.is_empty() {
+        return commit;
+    }
+    return commit + "-dirty";
+}
+
+pub fn make_entries(commit: &str, runs: usize, results: &Vec<StageResult>) -> Vec<HistoryEntry> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    return results.iter().map(|r| HistoryEntry{
+        commit: commit.to_string(),
+        timestamp,
+        runs,
+        day: r.day,
+        input: r.input.clone(),
+        stage: r.stage.clone(),
+        stats: r.stats.clone(),
+    }).collect();
+}
+
+pub fn append(path: &str, entries: &Vec<HistoryEntry>) -> std::io::Result<()> {
+    // one JSON object per line, so that appending never needs to rewrite the file
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+    for entry in entries {
+        let line = serde_json::to_string(entry).expect("Could not serialize history entry");
+        writeln!(file, "{}", line)?;
+    }
+    return Ok(());
+}
+
+pub fn load(path: &str) -> Result<Vec<HistoryEntry>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+
+    let mut entries = vec![];
+    for (i, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(line).map_err(|e| format!("{}:{}: {}", path, i+1, e))?;
+        entries.push(entry);
+    }
+    return Ok(entries);
+}
+
+fn same_commit(recorded: &str, asked: &str) -> bool {
+    if recorded == asked {
+        return true;
+    }
+    // dirty runs are only selected explicitly
+    if asked.is_empty() || recorded.ends_with("-dirty") || asked.ends_with("-dirty") {
+        return false;
+    }
+    // allow both shorter and longer hashes than the recorded one
+    return recorded.starts_with(asked) || asked.starts_with(recorded);
+}
+
+pub fn latest_for_commit(entries: &Vec<HistoryEntry>, commit: &str) -> BTreeMap<StageKey, Stats> {
+    // entries are in file order, so later runs of the same commit override older ones
+    let mut res = BTreeMap::new();
+    for entry in entries {
+        if same_commit(&entry.commit, commit) {
+            res.insert((entry.day, entry.input.clone(), entry.stage.clone()), entry.stats.clone());
+        }
+    }
+    return res;
+}
+
+pub fn compare(base: &BTreeMap<StageKey, Stats>, new: &BTreeMap<StageKey, Stats>, threshold: f64) -> Vec<Comparison> {
+    // only the timings of the same input are compared
+    let mut res = vec![];
+    for (key, new_stats) in new {
+        let base_stats = match base.get(key) {
+            Some(s) => s,
+            None => continue,
+        };
+        let base_ns = base_stats.median_ns.max(1);
+        let change = new_stats.median_ns as f64 / base_ns as f64 - 1.0;
+        res.push(Comparison{
+            day: key.0,
+            input: key.1.clone(),
+            stage: key.2.clone(),
+            base_ns: base_stats.median_ns,
+            new_ns: new_stats.median_ns,
+            change,
+            regressed: change > threshold,
+        });
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, day: u32, stage: &str, median: u64) -> HistoryEntry {
+        HistoryEntry{
+            commit: commit.to_string(),
+            timestamp: 0,
+            runs: 1,
+            day,
+            input: format!("aoc{:02}/input", day),
+            stage: stage.to_string(),
+            stats: Stats{min_ns: median, median_ns: median, mean_ns: median as f64, stddev_ns: 0.0},
+        }
+    }
+
+    #[test]
+    fn latest_wins() {
+        let entries = vec![
+            entry("abc1234", 1, "part1", 100),
+            entry("def5678", 1, "part1", 500),
+            entry("abc1234", 1, "part1", 120),
+        ];
+        let latest = latest_for_commit(&entries, "abc1234");
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[&(1, "aoc01/input".to_string(), "part1".to_string())].median_ns, 120);
+    }
+
+    #[test]
+    fn commit_prefixes() {
+        assert!(same_commit("abc1234", "abc"));
+        assert!(same_commit("abc1234", "abc1234ffff"));
+        assert!(!same_commit("abc1234-dirty", "abc"));
+        assert!(same_commit("abc1234-dirty", "abc1234-dirty"));
+        assert!(!same_commit("abc1234", "def"));
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let entries = vec![
+            entry("base", 1, "part1", 100),
+            entry("base", 1, "part2", 100),
+            entry("base", 2, "part1", 100),
+            entry("new", 1, "part1", 105),
+            entry("new", 1, "part2", 150),
+            entry("new", 3, "part1", 100),
+        ];
+        let res = compare(&latest_for_commit(&entries, "base"), &latest_for_commit(&entries, "new"), 0.1);
+        // day 2 and 3 are only in one of the commits
+        assert_eq!(res.len(), 2);
+        assert!(!res[0].regressed);
+        assert!(res[1].regressed);
+        assert!((res[1].change - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn inputs_kept_apart() {
+        let mut other = entry("new", 1, "part1", 500);
+        other.input = "other.txt".to_string();
+        let entries = vec![entry("base", 1, "part1", 100), entry("new", 1, "part1", 100), other];
+        let res = compare(&latest_for_commit(&entries, "base"), &latest_for_commit(&entries, "new"), 0.1);
+        assert_eq!(res.len(), 1);
+        assert!(!res[0].regressed);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let e = entry("abc1234", 7, "parse", 42);
+        let line = serde_json::to_string(&e).unwrap();
+        assert_eq!(serde_json::from_str::<HistoryEntry>(&line).unwrap(), e);
+    }
+}
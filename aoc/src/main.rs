@@ -0,0 +1,439 @@
+use std::env;
+use std::fs;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+use aoc_common::{is_no_answer, ParseError};
+use aoc_common::input::{self, STDIN_NAME};
+use aoc_common::animate::Player;
+use aoc_common::output::Format;
+use aoc_common::rng::Rng;
+
+mod answers;
+mod bench;
+mod days;
+mod history;
+
+use days::{get_animator, get_day, get_renderer, DAY_COUNT};
+
+fn default_input_key(day: u32) -> String {
+    // inputs are stored next to each day, keys are relative to the repository root
+    format!("aoc{:02}/input", day)
+}
+
+fn default_input(day: u32) -> String {
+    // found wherever the runner is started from
+    answers::workspace_root().join(default_input_key(day)).to_string_lossy().to_string()
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc run <day|all> [--part <1|2>] [--format <text|json>] [--record] [--render <image.png|ppm|svg>]\n               [--animate [--fps <n>] [--step]] [input|-]...");
+    eprintln!("       aoc bench <day|all> [--part <1|2>] [--runs <n>] [--json <file|->] [--history <file>] [--no-save] [input|-]");
+    eprintln!("       aoc compare <base-commit> [new-commit] [--threshold <percent>] [--history <file>]");
+    eprintln!("       aoc gen <day> [--seed <n>] [--size <n>] [--output <file>]");
+    process::exit(2);
+}
+
+struct Options {
+    days: Vec<u32>,
+    part: Option<u32>,
+    filenames: Vec<String>, // empty for the default inputs
+    runs: usize,
+    json: Option<String>,
+    history: String,
+    save: bool,
+    record: bool,
+    format: Format,
+    render: Option<String>,
+    animate: bool,
+    fps: Option<f64>,
+    step: bool,
+}
+
+fn parse_options(args: &[String], is_bench: bool) -> Options {
+    let mut days: Option<Vec<u32>> = None;
+    let mut part: Option<u32> = None;
+    let mut filenames: Vec<String> = vec![];
+    let mut runs: usize = 10;
+    let mut json: Option<String> = None;
+    let mut history = history::DEFAULT_HISTORY.to_string();
+    let mut save = true;
+    let mut record = false;
+    let mut format = Format::Text;
+    let mut render: Option<String> = None;
+    let mut animate = false;
+    let mut fps: Option<f64> = None;
+    let mut step = false;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if arg == "--part" {
+            part = match it.next().map(|s| s.as_str()) {
+                Some("1") => Some(1),
+                Some("2") => Some(2),
+                _ => usage(),
+            };
+        } else if is_bench && arg == "--runs" {
+            runs = match it.next().map(|s| s.parse::<usize>()) {
+                Some(Ok(n)) if n > 0 => n,
+                _ => usage(),
+            };
+        } else if is_bench && arg == "--json" {
+            json = Some(it.next().unwrap_or_else(|| usage()).clone());
+        } else if is_bench && arg == "--history" {
+            history = it.next().unwrap_or_else(|| usage()).clone();
+        } else if is_bench && arg == "--no-save" {
+            save = false;
+        } else if !is_bench && arg == "--record" {
+            record = true;
+        } else if !is_bench && arg == "--format" {
+            format = it.next().and_then(|name| Format::from_name(name)).unwrap_or_else(|| usage());
+        } else if !is_bench && arg == "--render" {
+            render = Some(it.next().unwrap_or_else(|| usage()).clone());
+        } else if !is_bench && arg == "--animate" {
+            animate = true;
+        } else if !is_bench && arg == "--fps" {
+            fps = match it.next().map(|s| s.parse::<f64>()) {
+                Some(Ok(f)) if f > 0.0 => Some(f),
+                _ => usage(),
+            };
+        } else if !is_bench && arg == "--step" {
+            step = true;
+        } else if days.is_none() {
+            if arg == "all" {
+                days = Some((1..=DAY_COUNT).collect());
+            } else {
+                match arg.parse::<u32>() {
+                    Ok(day) if (1..=DAY_COUNT).contains(&day) => days = Some(vec![day]),
+                    _ => usage(),
+                }
+            }
+        } else {
+            filenames.push(arg.clone());
+        }
+    }
+
+    let days = days.unwrap_or_else(|| usage());
+    if filenames.len() > 0 && days.len() > 1 {
+        // each day has its own input, explicit files only make sense for a single day
+        usage();
+    }
+    if render.is_some() && (days.len() > 1 || filenames.len() > 1) {
+        // a single picture is written
+        usage();
+    }
+    if render.is_some() && get_renderer(days[0]).is_none() {
+        eprintln!("error: day {} has nothing to render, only days 10, 16, 17 and 18 do", days[0]);
+        process::exit(2);
+    }
+    if (fps.is_some() || step) && !animate {
+        usage();
+    }
+    if animate && (days.len() > 1 || filenames.len() > 1) {
+        usage();
+    }
+    if animate && get_animator(days[0]).is_none() {
+        eprintln!("error: day {} has no animation, only days 14, 16 and 17 do", days[0]);
+        process::exit(2);
+    }
+    if animate && format == Format::Json {
+        // the frames would be mixed with the JSON lines on stdout
+        eprintln!("error: --animate draws to the terminal, it cannot be used with --format json");
+        process::exit(2);
+    }
+    if step && filenames.iter().any(|f| f == STDIN_NAME) {
+        // Enter is read from stdin between the frames
+        eprintln!("error: --step reads the terminal, the input cannot come from stdin");
+        process::exit(2);
+    }
+    if is_bench && filenames.len() > 1 {
+        // a single input per day, the history keys timings by day and input
+        usage();
+    }
+
+    return Options{days, part, filenames, runs, json, history, save, record, format, render, animate, fps, step};
+}
+
+fn input_names(options: &Options, day: u32) -> Vec<String> {
+    if options.filenames.is_empty() {
+        return vec![default_input(day)];
+    }
+    return options.filenames.clone();
+}
+
+fn read_input(filename: &str) -> String {
+    return input::read_input(filename).unwrap_or_else(|e| {
+        eprintln!("error: could not read {}: {}", filename, e);
+        process::exit(1);
+    });
+}
+
+fn parse_failed(filename: &str, e: ParseError) -> ! {
+    eprintln!("{}", e.with_file(filename));
+    process::exit(1);
+}
+
+fn render_to(day: u32, contents: &str, path: &str) -> Result<(), ParseError> {
+    let picture = get_renderer(day).expect("days without a renderer are refused with the options").render(contents)?;
+    picture.save(path).unwrap_or_else(|e| {
+        eprintln!("error: could not write {}: {}", path, e);
+        process::exit(1);
+    });
+    return Ok(());
+}
+
+fn cmd_run(args: &[String]) {
+    let options = parse_options(args, false);
+
+    let (root, answers_path) = (answers::workspace_root(), answers::answers_path());
+    let mut answers = answers::Answers::load(&answers_path).unwrap_or_else(|e| {
+        eprintln!("error: could not read known answers: {}", e);
+        process::exit(2);
+    });
+
+    let mut wrong_count = 0;
+    let mut failed = false;
+    let mut missing: Vec<(u32, String, u32, String)> = vec![];
+    for &day in &options.days {
+        let filenames = input_names(&options, day);
+        for filename in &filenames {
+            let contents = match input::read_input(filename) {
+                Ok(contents) => contents,
+                Err(e) => {
+                    eprintln!("error: could not read {}: {}", filename, e);
+                    failed = true;
+                    continue;
+                }
+            };
+            let records = get_day(day).run(&contents, filename, options.part);
+            let records = match records {
+                Ok(records) => records,
+                Err(e) => {
+                    // keep going, so that every input of the folder gets reported
+                    eprintln!("{}", e.with_file(filename));
+                    failed = true;
+                    continue;
+                }
+            };
+
+            if let Some(path) = &options.render {
+                render_to(day, &contents, path).unwrap_or_else(|e| parse_failed(filename, e));
+            }
+            if options.animate {
+                // 10 frames per second by default
+                let mut player = Player::new(options.fps.unwrap_or(10.0), options.step);
+                let animator = get_animator(day).expect("days without an animation are refused with the options");
+                animator.animate(&contents, &mut player).unwrap_or_else(|e| parse_failed(filename, e));
+            }
+
+            if filenames.len() > 1 && options.format == Format::Text {
+                println!("== {}", filename);
+            }
+            for record in records {
+                let (day, part) = (record.day, record.part);
+                // stdin has no stable name, its answers can be neither checked nor recorded
+                let key = answers::input_key(&root, filename);
+                let check = if filename == STDIN_NAME {
+                    None
+                } else {
+                    Some(answers.check(day, &key, part, &record.answer))
+                };
+
+                let mut text = format!("[{:02}] part {}: {}", day, part, record.answer);
+                if let Some(answers::Check::Wrong(expected)) = &check {
+                    text += &format!(" (expected {})", expected);
+                    wrong_count += 1;
+                }
+                if options.format == Format::Json {
+                    println!("{}", record.to_json());
+                    // keep stdout machine-readable, mismatches go to stderr
+                    if matches!(check, Some(answers::Check::Wrong(_))) {
+                        eprintln!("{}", text);
+                    }
+                } else {
+                    println!("{}", text);
+                }
+
+                // a part with no answer on this input has nothing to record
+                if check == Some(answers::Check::Missing) && !is_no_answer(&record.answer) {
+                    missing.push( (day, key, part, record.answer) );
+                }
+            }
+        }
+    }
+
+    if missing.len() > 0 {
+        if options.record {
+            for (day, key, part, answer) in &missing {
+                answers.set(*day, key, *part, answer);
+            }
+            answers.save(&answers_path).expect("Could not write known answers");
+            eprintln!("Recorded {} answers in {}", missing.len(), answers_path.display());
+        } else {
+            eprintln!("{} answers are not in {}, run again with --record to store them", missing.len(), answers_path.display());
+        }
+    }
+    if wrong_count > 0 {
+        eprintln!("error: {} answers differ from {}", wrong_count, answers_path.display());
+    }
+    if failed || wrong_count > 0 {
+        process::exit(1);
+    }
+}
+
+fn cmd_bench(args: &[String]) {
+    let options = parse_options(args, true);
+
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: benchmarking a debug build, use `cargo run --release` for meaningful timings");
+    }
+
+    let root = answers::workspace_root();
+    let mut results = vec![];
+    for &day in &options.days {
+        let filename = input_names(&options, day).remove(0);
+        let contents = read_input(&filename);
+        let timings = get_day(day).bench(&contents, options.part, options.runs).unwrap_or_else(|e| parse_failed(&filename, e));
+        for result in bench::stage_results(day, &answers::input_key(&root, &filename), &timings) {
+            if options.json.as_deref() != Some("-") {
+                bench::print_result(&result);
+            }
+            results.push(result);
+        }
+    }
+
+    if options.save {
+        let entries = history::make_entries(&history::current_commit(), options.runs, &results);
+        history::append(&options.history, &entries).expect("Could not write benchmark history");
+    }
+
+    if let Some(path) = &options.json {
+        let report = bench::BenchReport{runs: options.runs, results};
+        let text = serde_json::to_string_pretty(&report).expect("Could not serialize report");
+        if path == "-" {
+            println!("{}", text);
+        } else {
+            fs::write(path, text + "\n").expect("Could not write report");
+        }
+    }
+}
+
+fn cmd_compare(args: &[String]) {
+    let mut commits: Vec<String> = vec![];
+    let mut threshold: f64 = 10.0;
+    let mut history_file = history::DEFAULT_HISTORY.to_string();
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if arg == "--threshold" {
+            threshold = match it.next().map(|s| s.trim_end_matches('%').parse::<f64>()) {
+                Some(Ok(t)) if t >= 0.0 => t,
+                _ => usage(),
+            };
+        } else if arg == "--history" {
+            history_file = it.next().unwrap_or_else(|| usage()).clone();
+        } else if commits.len() < 2 {
+            commits.push(arg.clone());
+        } else {
+            usage();
+        }
+    }
+    if commits.is_empty() {
+        usage();
+    }
+
+    let entries = history::load(&history_file).unwrap_or_else(|e| {
+        eprintln!("Could not read benchmark history: {}", e);
+        process::exit(2);
+    });
+    // without a second commit, compare against the most recent run
+    let new_commit = match commits.get(1) {
+        Some(c) => c.clone(),
+        None => match entries.last() {
+            Some(e) => e.commit.clone(),
+            None => {
+                eprintln!("Benchmark history is empty");
+                process::exit(2);
+            }
+        },
+    };
+
+    let base = history::latest_for_commit(&entries, &commits[0]);
+    let new = history::latest_for_commit(&entries, &new_commit);
+    if base.is_empty() || new.is_empty() {
+        eprintln!("No benchmark recorded for {}", if base.is_empty() { &commits[0] } else { &new_commit });
+        process::exit(2);
+    }
+
+    println!("Comparing {} to {} (threshold {}%)", commits[0], new_commit, threshold);
+    let comparisons = history::compare(&base, &new, threshold / 100.0);
+    for c in &comparisons {
+        println!(
+            "[{:02}] {:<5}  {:>10} -> {:>10}  {:+6.1}%{}{}",
+            c.day,
+            c.stage,
+            bench::format_ns(c.base_ns as f64),
+            bench::format_ns(c.new_ns as f64),
+            c.change * 100.0,
+            if c.regressed { "  REGRESSION" } else { "" },
+            if c.input != default_input_key(c.day) { format!("  (on {})", c.input) } else { String::new() },
+        );
+    }
+
+    if comparisons.iter().any(|c| c.regressed) {
+        process::exit(1);
+    }
+}
+
+fn cmd_gen(args: &[String]) {
+    let mut day: Option<u32> = None;
+    let mut seed: Option<u64> = None;
+    let mut size: usize = 20;
+    let mut output: Option<String> = None;
+
+    let mut it = args.iter();
+    while let Some(arg) = it.next() {
+        if arg == "--seed" {
+            seed = Some(it.next().and_then(|s| s.parse::<u64>().ok()).unwrap_or_else(|| usage()));
+        } else if arg == "--size" {
+            size = it.next().and_then(|s| s.parse::<usize>().ok()).unwrap_or_else(|| usage());
+        } else if arg == "--output" {
+            output = Some(it.next().unwrap_or_else(|| usage()).clone());
+        } else if day.is_none() {
+            day = match arg.parse::<u32>() {
+                Ok(day) if (1..=DAY_COUNT).contains(&day) => Some(day),
+                _ => usage(),
+            };
+        } else {
+            usage();
+        }
+    }
+    let day = day.unwrap_or_else(|| usage());
+
+    // without a seed, pick one and tell it so that the input can be generated again
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+        eprintln!("Generating day {} with --seed {}", day, seed);
+        seed
+    });
+
+    let contents = get_day(day).generate(&mut Rng::new(seed), size);
+    match output {
+        Some(path) => fs::write(&path, contents).unwrap_or_else(|e| {
+            eprintln!("error: could not write {}: {}", path, e);
+            process::exit(1);
+        }),
+        None => print!("{}", contents),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => cmd_run(&args[1..]),
+        Some("bench") => cmd_bench(&args[1..]),
+        Some("compare") => cmd_compare(&args[1..]),
+        Some("gen") => cmd_gen(&args[1..]),
+        _ => usage(),
+    }
+}
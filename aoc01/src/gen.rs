@@ -0,0 +1,48 @@
+use aoc_common::Generator;
+use aoc_common::gen::random_word;
+use aoc_common::rng::Rng;
+use crate::{Day01, STRDIGITS};
+
+impl Generator for Day01 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // calibration lines of letters, digits and spelled digits, with at least one digit each
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let mut chunks: Vec<String> = vec![];
+            for _ in 0..rng.range(1, 6) {
+                chunks.push(match rng.below(4) {
+                    0 => STRDIGITS[rng.range(1, 9) as usize].to_string(),
+                    1 => rng.range(1, 9).to_string(),
+                    _ => {
+                        let len = rng.below(5) + 1;
+                        random_word(rng, len, "abcdefghijklmnopqrstuvwxyz")
+                    },
+                });
+            }
+            if !chunks.iter().any(|c| c.chars().any(|c| c.is_ascii_digit())) {
+                let pos = rng.below(chunks.len() + 1);
+                chunks.insert(pos, rng.range(1, 9).to_string());
+            }
+            res += &chunks.concat();
+            res.push('\n');
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day01::parse(&Day01::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.iter().filter(|l| l.len() > 0).count(), 20);
+            Day01::part1(&input);
+            Day01::part2(&input);
+        }
+    }
+}
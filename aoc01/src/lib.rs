@@ -0,0 +1,140 @@
+use aoc_common::{ParseError, Solution};
+//use std::string;
+//use std::iter;
+
+mod gen;
+
+const STRDIGITS: [&str; 10] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(contents: &str) -> Result<Vec<String>, ParseError> {
+        Ok(contents.split('\n').map(|line| line.to_string()).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> u32 {
+        let mut res : u32 = 0;
+
+        for line in lines {
+            let mut first_digit : Option<char> = None;
+            let mut last_digit : Option<char> = None;
+
+            for cr in line.chars() {
+                match cr {
+                    '0'..='9' => {
+                        if first_digit.is_none() {
+                            first_digit = Some(cr);
+                        }
+                        last_digit = Some(cr);
+                    },
+                    _ => {}
+                }
+            }
+
+            match (first_digit,last_digit) {
+                (Some(x),Some(y)) => {
+                    res += x.to_digit(10).unwrap()*10 + y.to_digit(10).unwrap();
+                },
+                (_,_) => {}
+            }
+        }
+
+        return res;
+    }
+
+    fn part2(lines: &Vec<String>) -> u32 {
+        let mut res2 : u32 = 0;
+        let mut lastletters = "".to_owned();
+
+        for line in lines {
+            let mut first : Option<char> = None;
+            let mut last : Option<char> = None;
+
+            for cr in line.chars() {
+                match cr {
+                    '0'..='9' => {
+                        if first.is_none() {
+                            first = Some(cr);
+                        }
+                        last = Some(cr);
+                    },
+                    'a'..='z' => {
+                        lastletters.push(cr);
+
+                        let mut digmatch : Option<char> = None;
+
+                        for (val,dig) in STRDIGITS.iter().enumerate() {
+                            if dig.len() > lastletters.len() {
+                                continue;
+                            }
+                            let mut matched = true;
+                            for i in 0..dig.len() {
+                                if dig.as_bytes()[i] != lastletters.as_bytes()[lastletters.len()-dig.len()+i] {
+                                    matched = false;
+                                }
+                            }
+                            if matched {
+                                digmatch = char::from_digit(val.try_into().unwrap(), 10);
+                            }
+                        }
+
+                        if digmatch.is_some() {
+                            if first.is_none() {
+                                first = digmatch;
+                            }
+                            last = digmatch;
+                        }
+                    },
+                    _ => {}
+                }
+            }
+
+            match (first,last) {
+                (Some(x),Some(y)) => {
+                    res2 += x.to_digit(10).unwrap()*10 + y.to_digit(10).unwrap();
+                },
+                (_,_) => {}
+            }
+        }
+
+        return res2;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example_part1() {
+        let input = Day01::parse("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n").unwrap();
+        assert_eq!(Day01::part1(&input), 142);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = Day01::parse("two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n").unwrap();
+        assert_eq!(Day01::part2(&input), 281);
+    }
+
+    fuzz_parse!(Day01);
+}
@@ -0,0 +1,39 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::{Day02, COLORS};
+
+impl Generator for Day02 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut res = String::new();
+        for id in 1..=size.max(1) {
+            let mut hands: Vec<String> = vec![];
+            for _ in 0..rng.range(1, 6) {
+                // each colour at most once per hand
+                let mut colors = COLORS.to_vec();
+                rng.shuffle(&mut colors);
+                let count = rng.range(1, colors.len() as i64) as usize;
+                let cubes: Vec<String> = colors[..count].iter().map(|c| format!("{} {}", rng.range(1, 20), c)).collect();
+                hands.push(cubes.join(", "));
+            }
+            res += &format!("Game {}: {}\n", id, hands.join("; "));
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day02::parse(&Day02::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.len(), 20);
+            Day02::part1(&input);
+            Day02::part2(&input);
+        }
+    }
+}
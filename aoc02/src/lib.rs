@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::cmp;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::lines;
+
+mod gen;
+
+pub struct Game {
+    id: u32,
+    hands: Vec<Vec<(i32,String)>>, // (count, color) for each hand
+}
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = i32;
+
+    fn parse(contents: &str) -> Result<Vec<Game>, ParseError> {
+        let mut games: Vec<Game> = vec![];
+
+        for line in lines(contents) {
+            if line.len() < 2{
+                continue;
+            }
+
+            //println!("Lines: {line}");
+
+            let (header, hands) = line.split_once(':').ok_or_else(|| line.error_line("expected `Game <id>:` before the hands"))?;
+            let id_str = header.split(' ').next_back().unwrap();
+            let gameid = line.number::<u32>(id_str)?;
+
+            let mut game = Game{id: gameid, hands: vec![]};
+            for hand in hands.split(';') {
+                let mut cubes: Vec<(i32,String)> = vec![];
+                for part in hand.split(',') {
+                    let set = part.split(' ').collect::<Vec<&str>>();
+                    if set.len() != 3 {
+                        return Err(line.error(part, "expected `<count> <colour>`"));
+                    }
+                    let count = line.number::<i32>(set[1])?;
+                    let color = set[2];
+                    if !COLORS.contains(&color) {
+                        return Err(line.error(color, &format!("unknown colour `{}`", color)));
+                    }
+                    cubes.push( (count, color.to_string()) );
+                }
+                game.hands.push(cubes);
+            }
+            games.push(game);
+        }
+
+        return Ok(games);
+    }
+
+    fn part1(games: &Vec<Game>) -> u32 {
+        let bagsize = HashMap::from([
+            ("red", 12),
+            ("green", 13),
+            ("blue", 14)
+        ]);
+
+        let mut res = 0;
+        for game in games {
+            let mut isvalid = true;
+            for hand in &game.hands {
+                for (count, color) in hand {
+                    let maxi = *bagsize.get(color.as_str()).expect("Count of cubes");
+
+                    if *count > maxi {
+                        isvalid = false;
+                    }
+                }
+            }
+            if isvalid {
+                res += game.id;
+            }
+        }
+
+        return res;
+    }
+
+    fn part2(games: &Vec<Game>) -> i32 {
+        let mut res2 = 0;
+        for game in games {
+            let mut miniset = HashMap::from([
+                ("red", 0),
+                ("green", 0),
+                ("blue", 0)
+            ]);
+
+            for hand in &game.hands {
+                for (count, color) in hand {
+                    let color = color.as_str();
+                    *miniset.get_mut(color).unwrap() = cmp::max(*miniset.get(color).expect("Unknown color"), *count);
+                }
+            }
+
+            let gamesum = miniset.get("red").expect("No red") * miniset.get("green").expect("No green") * miniset.get("blue").expect("No blue");
+
+            res2 += gamesum;
+        }
+
+        return res2;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part1(&input), 8);
+        assert_eq!(Day02::part2(&input), 2286);
+    }
+
+    #[test]
+    fn test_parse_unknown_colour() {
+        let e = Day02::parse("Game 1: 3 blue, 4 red\nGame 2: 1 purple, 2 green\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (2, 11, 6));
+    }
+
+    #[test]
+    fn test_parse_bad_count() {
+        let e = Day02::parse("Game 1: x blue\n").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 9));
+    }
+
+    fuzz_parse!(Day02);
+}
@@ -0,0 +1,53 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day03;
+
+const SYMBOLS: &str = "*#+$/@=%&-";
+
+impl Generator for Day03 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // a square schematic, numbers never follow each other directly so that they stay apart
+        let side = size.max(3);
+        let symbols: Vec<char> = SYMBOLS.chars().collect();
+        let mut res = String::new();
+        for _ in 0..side {
+            let mut row = String::new();
+            let mut after_number = false;
+            while row.len() < side {
+                let digits = rng.range(1, 3) as usize;
+                let roll = rng.below(10);
+                if roll < 3 && !after_number && row.len() + digits <= side {
+                    row += &rng.range(10i64.pow(digits as u32 - 1), 10i64.pow(digits as u32) - 1).to_string();
+                    after_number = true;
+                    continue;
+                }
+                if roll < 5 {
+                    row.push(*rng.choose(&symbols));
+                } else {
+                    row.push('.');
+                }
+                after_number = false;
+            }
+            res += &row;
+            res.push('\n');
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day03::parse(&Day03::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.symbol_map.height(), 20);
+            Day03::part1(&input);
+            Day03::part2(&input);
+        }
+    }
+}
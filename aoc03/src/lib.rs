@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use aoc_common::{ParseError, Solution};
+use aoc_common::grid::Grid;
+use aoc_common::parse::lines;
+
+mod gen;
+
+struct NumInfo {
+    xbeg: usize,
+    xend: usize, // past the end
+    y: usize,
+    value: i32
+}
+
+
+fn extend_square<T: PartialEq + Copy>(mat: &Grid<T>, empty: T) -> Grid<T> {
+    /*
+    In the matrix, extend each value !=empty so that it is present in a 3x3 square centered on the input point.
+    If two squares intersect, fill in reading order.
+    */
+
+    // output
+    let mut new_mat = Grid::new(mat.height(), mat.width(), empty);
+
+    // go through the input
+    for ((y,x),xv) in mat.iter() {
+        if *xv != empty {
+            // go around
+            for (y2,x2) in mat.neighbours8(y, x).chain([(y, x)]) {
+                let cell : &mut T = &mut new_mat[(y2, x2)];
+                if *cell == empty {
+                    *cell = *xv;
+                }
+            }
+        }
+    }
+
+    return new_mat;
+}
+
+pub struct Schematic {
+    symbol_map: Grid<bool>,
+    gear_map: Grid<i32>,
+    numbers: Vec<NumInfo>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Schematic;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(contents: &str) -> Result<Schematic, ParseError> {
+        // Parse input
+        let mut symbol_map: Vec<Vec<bool>> = vec![];
+        let mut gear_map: Vec<Vec<i32>> = vec![];
+        let mut gear_count: i32 = 0;
+        let mut numbers: Vec<NumInfo> = vec![];
+
+        for line in lines(contents) {
+            if line.len() < 2 {
+                continue;
+            }
+            let mut symbol_line: Vec<bool> = vec![];
+            let mut gear_line: Vec<i32> = vec![];
+            let mut current_num : String = "".to_string();
+            for (pos, car) in line.char_indices() {
+                match car {
+                    '0'..='9' => {
+                        current_num.push(car);
+                    },
+                    _ => {
+                        if current_num.len() > 0 {
+                            let newnum = NumInfo{
+                                xbeg: symbol_line.len() -current_num.len(),
+                                xend: symbol_line.len(),
+                                y: symbol_map.len(),
+                                value: line.number::<i32>(&line[(pos-current_num.len())..pos])?
+                            };
+                            numbers.push(newnum);
+
+                            current_num = "".to_string();
+                        }
+                    },
+                }
+                match car {
+                    '0'..='9' | '.' => { symbol_line.push(false); },
+                    '*' => { symbol_line.push(true); },
+                    _ => { symbol_line.push(true); },
+                }
+
+                if car == '*' {
+                    gear_count += 1;
+                    gear_line.push(gear_count);
+                } else {
+                    gear_line.push(0);
+                }
+            }
+
+            // commit trailing number
+            if current_num.len() > 0 {
+                let newnum = NumInfo{
+                    xbeg: symbol_line.len() -current_num.len(),
+                    xend: symbol_line.len(),
+                    y: symbol_map.len(),
+                    value: line.number::<i32>(&line[(line.len()-current_num.len())..])?
+                };
+                numbers.push(newnum);
+            }
+
+            if symbol_map.len() > 0 && symbol_line.len() != symbol_map[0].len() {
+                return Err(line.error_line(&format!("expected {} columns like the first line, found {}", symbol_map[0].len(), symbol_line.len())));
+            }
+
+            symbol_map.push(symbol_line);
+            gear_map.push(gear_line);
+        }
+
+        if symbol_map.len() == 0 {
+            return Err(ParseError::new("expected a schematic, found nothing"));
+        }
+
+        return Ok(Schematic{symbol_map: Grid::from_rows(symbol_map), gear_map: Grid::from_rows(gear_map), numbers});
+    }
+
+    fn part1(schematic: &Schematic) -> i32 {
+        // Convolution
+        let symbol_map = extend_square(&schematic.symbol_map, false);
+
+        // Check the map
+        //print!("{}", symbol_map.display(|b| if *b { '#' } else { '.' }));
+
+        // Get numbers around any symbol
+        let mut part_numbers : Vec<i32> = vec![];
+        for numinfo in &schematic.numbers {
+            let mut is_part : bool = false;
+            for x in numinfo.xbeg..numinfo.xend {
+                if symbol_map[(numinfo.y, x)] {
+                    is_part = true;
+                }
+            }
+            if is_part {
+                part_numbers.push(numinfo.value);
+                //println!("Found part number {} at {},{}", numinfo.value, numinfo.xbeg, numinfo.y);
+            }
+        }
+
+        let res: i32 = part_numbers.iter().sum();
+        return res;
+    }
+
+    fn part2(schematic: &Schematic) -> i32 {
+        // Convolution
+        let gear_map = extend_square(&schematic.gear_map, 0);
+
+        // Get numbers around gear symbols
+        let mut gear_numbers: HashMap<i32, HashSet<i32>> = HashMap::new();
+        for numinfo in schematic.numbers.iter() {
+            for x in numinfo.xbeg..numinfo.xend {
+                let gear_id = gear_map[(numinfo.y, x)];
+                if gear_id != 0 {
+                    let numset = gear_numbers.entry(gear_id).or_default();
+                    numset.insert(numinfo.value);
+                }
+            }
+        }
+
+        //dbg!(&gear_numbers);
+
+        // Iterate valid gears
+        let mut gearsum = 0;
+        for (_,gear_numset) in gear_numbers {
+            if gear_numset.len() == 2 {
+                let mut it = gear_numset.iter();
+                gearsum += it.next().unwrap() * it.next().unwrap();
+            }
+        }
+
+        return gearsum;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day03::parse("467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..").unwrap();
+        assert_eq!(Day03::part1(&input), 4361);
+        assert_eq!(Day03::part2(&input), 467835);
+    }
+
+    fuzz_parse!(Day03);
+}
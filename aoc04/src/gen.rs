@@ -0,0 +1,49 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day04;
+
+const WINNING_COUNT: usize = 10;
+const SCRATCHED_COUNT: usize = 25;
+
+fn numbers_text(numbers: &Vec<i32>) -> String {
+    numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<String>>().join(" ")
+}
+
+impl Generator for Day04 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // cards never win copies of cards past the end of the table
+        let count = size.max(1);
+        let mut res = String::new();
+        for id in 1..=count {
+            let mut numbers: Vec<i32> = (1..100).collect();
+            rng.shuffle(&mut numbers);
+            let winning = numbers[..WINNING_COUNT].to_vec();
+
+            let matches = rng.below(WINNING_COUNT.min(count - id) + 1);
+            let mut scratched: Vec<i32> = winning[..matches].to_vec();
+            scratched.extend(&numbers[WINNING_COUNT..(WINNING_COUNT + SCRATCHED_COUNT - matches)]);
+            rng.shuffle(&mut scratched);
+
+            res += &format!("Card {:>3}: {} | {}\n", id, numbers_text(&winning), numbers_text(&scratched));
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day04::parse(&Day04::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.len(), 20);
+            assert!(input.iter().enumerate().all(|(i, card)| card.match_count() as usize <= 19 - i));
+            Day04::part1(&input);
+            Day04::part2(&input);
+        }
+    }
+}
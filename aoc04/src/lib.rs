@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+use num_bigint::BigUint;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+
+
+fn parse_integers(line: &Line, text: &str) -> Result<Vec<i32>, ParseError> {
+    return text.split(' ')
+               .filter(|s| s.len() > 0)
+               .map(|s| line.number::<i32>(s))
+               .collect();
+}
+
+fn zeropow(x : i32) -> i32 {
+    if x == 0 {
+        return 0;
+    } else {
+        return 2_i32.pow((x-1) as u32);
+    }
+}
+
+pub struct Card {
+    numbers: Vec<i32>,
+    scratched: Vec<i32>,
+}
+
+impl Card {
+    fn match_count(&self) -> i32 {
+        self.scratched.iter().filter(|x| self.numbers.contains(x)).count().try_into().unwrap()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Vec<Card>;
+    type Answer1 = i32;
+    type Answer2 = BigUint;
+
+    fn parse(contents: &str) -> Result<Vec<Card>, ParseError> {
+        let mut cards: Vec<Card> = vec![];
+
+        for line in lines(contents) {
+            if line.len() < 2 {
+                continue;
+            }
+            let (_, data) = line.split_once(':').ok_or_else(|| line.error_line("expected `Card <id>:` before the numbers"))?;
+            let (card_str, scratched_str) = data.split_once('|').ok_or_else(|| line.error(data, "expected winning numbers and scratched numbers separated by `|`"))?;
+            let card = parse_integers(&line, card_str)?;
+            let scratched = parse_integers(&line, scratched_str)?;
+
+            cards.push(Card{numbers: card, scratched: scratched});
+        }
+
+        return Ok(cards);
+    }
+
+    fn part1(cards: &Vec<Card>) -> i32 {
+        let mut res1 : i32 = 0;
+        for card in cards {
+            res1 += zeropow(card.match_count());
+        }
+        return res1;
+    }
+
+    fn part2(cards: &Vec<Card>) -> BigUint {
+        let mut card_queue : VecDeque<BigUint> = VecDeque::from([]);
+        let mut res2 : BigUint = num_traits::Zero::zero();
+
+        for card in cards {
+            let match_count = card.match_count();
+
+            let current_mul : BigUint = card_queue.pop_front().unwrap_or(num_traits::Zero::zero()) +1_u32;
+            //println!("Adding {current_mul} cards to next {match_count}");
+            res2 += current_mul.clone();
+
+            for i in 0..match_count {
+                if card_queue.len() <= i.try_into().unwrap() {
+                    card_queue.push_back(current_mul.clone());
+                } else {
+                    *card_queue.get_mut(i as usize).unwrap() += current_mul.clone();
+                }
+            }
+        }
+
+        return res2;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part1(&input), 13);
+        assert_eq!(Day04::part2(&input), BigUint::from(30u32));
+    }
+
+    fuzz_parse!(Day04);
+}
@@ -0,0 +1,75 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day05;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+const MAX_VALUE: i64 = 4_000_000_000;
+
+fn random_map(rng: &mut Rng, segment_count: usize) -> Vec<(i64, i64, i64)> {
+    /*
+    Contiguous source segments, sent in a random order to contiguous destinations:
+    sources never overlap, and neither do destinations.
+    Returns (destination, source, length) triples.
+    */
+    let mut cuts: Vec<i64> = (0..=segment_count).map(|_| rng.range(0, MAX_VALUE)).collect();
+    cuts.sort();
+    cuts.dedup();
+    let mut segments: Vec<(i64, i64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+    let total: i64 = segments.iter().map(|(_, len)| len).sum();
+    let mut destination = rng.range(0, MAX_VALUE - total);
+    rng.shuffle(&mut segments);
+
+    let mut res = vec![];
+    for (source, len) in segments {
+        res.push( (destination, source, len) );
+        destination += len;
+    }
+    return res;
+}
+
+impl Generator for Day05 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut seeds: Vec<String> = vec![];
+        for _ in 0..(size/2).clamp(1, 10) {
+            let start = rng.range(0, MAX_VALUE - 1);
+            let len = rng.range(1, (MAX_VALUE / 20).min(MAX_VALUE - start));
+            seeds.push(format!("{} {}", start, len));
+        }
+
+        let mut res = format!("seeds: {}\n", seeds.join(" "));
+        for name in MAP_NAMES {
+            res += &format!("\n{} map:\n", name);
+            for (destination, source, len) in random_map(rng, size.max(1)) {
+                res += &format!("{} {} {}\n", destination, source, len);
+            }
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day05::parse(&Day05::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.maps.len(), 7);
+            Day05::part1(&input);
+            Day05::part2(&input);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use itertools::Itertools;
+use aoc_common::{ParseError, Solution};
+use aoc_common::interval::{Interval, IntervalSet};
+use aoc_common::parse::blocks;
+
+mod gen;
+
+#[derive(Debug)]
+struct MapSegment {
+    source : Interval<i64>,
+    offset : i64
+}
+
+
+fn get_after_map(x : &i64, map : &Vec<MapSegment>) -> i64 {
+    for seg in map {
+        if seg.source.contains(*x) {
+            return x + seg.offset;
+        }
+    }
+    return *x;
+}
+
+fn get_after_map_range(range: &Interval<i64>, map : &Vec<MapSegment>) -> Vec<Interval<i64>> {
+    let mut res : Vec<Interval<i64>> = vec![];
+    let mut to_map : Vec<Interval<i64>> = vec![ *range ];
+
+    for map_seg in map {
+        let mut new_to_map : Vec<Interval<i64>> = vec![];
+
+        for r in to_map {
+            match r.intersection(&map_seg.source) {
+                // disjoint segments
+                None => new_to_map.push(r),
+                Some(mapped) => {
+                    // the bits at start and at end are unmapped
+                    let (below, _) = r.split_at(map_seg.source.start);
+                    let (_, above) = r.split_at(map_seg.source.end);
+                    new_to_map.extend(below.into_iter().chain(above));
+                    // mapped part
+                    res.push(mapped.shift(map_seg.offset));
+                }
+            }
+        }
+
+        to_map = new_to_map;
+    }
+
+    // remaining unmapped
+    res.extend(to_map);
+
+    return res;
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Vec<MapSegment>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Almanac;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Almanac, ParseError> {
+        let blocks = blocks(contents);
+        if blocks.len() == 0 {
+            return Err(ParseError::new("expected `seeds:` on the first line"));
+        }
+
+        let seeds_line = blocks[0][0];
+        let (_, seeds_str) = seeds_line.text.split_once(':').ok_or_else(|| seeds_line.error_line("expected `seeds:` on the first line"))?;
+        let seeds : Vec<i64> = seeds_line.intseq(seeds_str)?;
+        if seeds.len() == 0 || !seeds.len().is_multiple_of(2) {
+            return Err(seeds_line.error(seeds_str, "expected a non-empty, even count of seeds"));
+        }
+        let mut maps : Vec<Vec<MapSegment>> = vec![];
+
+        for block in &blocks[1..] {
+            // the first line is the "<>-to-<> map:" one
+            let mut current_map : Vec<MapSegment> = vec![];
+            for line in &block[1..] {
+                let mappings = line.intseq(line)?;
+
+                let [begb, bega, len]: [_;3] = mappings.try_into().map_err(|_| line.error_line("expected `<destination> <source> <length>`"))?;
+
+                current_map.push(MapSegment{
+                    source: Interval::new(bega, bega+len),
+                    offset: begb-bega
+                })
+            }
+
+            //dbg!(&current_map);
+
+            if current_map.len() > 0 {
+                maps.push(current_map);
+            }
+        }
+
+        return Ok(Almanac{seeds, maps});
+    }
+
+    fn part1(almanac: &Almanac) -> i64 {
+        let mut seeds = almanac.seeds.clone();
+
+        for current_map in &almanac.maps {
+            // apply the map
+            seeds = seeds.iter().map(|x| get_after_map(x, current_map)).collect();
+            //dbg!(&seeds);
+        }
+
+        seeds.sort();
+        return *seeds.get(0).unwrap();
+    }
+
+    fn part2(almanac: &Almanac) -> i64 {
+        let mut seed_ranges : IntervalSet<i64> = almanac.seeds.iter().tuples().map(|(a,b)| Interval::new(*a, a+b)).collect();
+
+        for current_map in &almanac.maps {
+            // apply the map, overlapping ranges get merged
+            seed_ranges = seed_ranges.iter().flat_map(|r| get_after_map_range(r, current_map)).collect();
+            //dbg!(&seed_ranges);
+        }
+
+        return seed_ranges.min().unwrap();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    fn seed_to_soil() -> Vec<MapSegment> {
+        vec![
+            MapSegment{source: Interval::new(98, 100), offset: -48},
+            MapSegment{source: Interval::new(50, 98), offset: 2},
+        ]
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&input), 35);
+        assert_eq!(Day05::part2(&input), 46);
+    }
+
+    #[test]
+    fn test_get_after_map_range_inside() {
+        assert_eq!(get_after_map_range(&Interval::new(79,93), &seed_to_soil()), vec![Interval::new(81,95)]);
+    }
+
+    #[test]
+    fn test_get_after_map_range_outside() {
+        assert_eq!(get_after_map_range(&Interval::new(10,30), &seed_to_soil()), vec![Interval::new(10,30)]);
+    }
+
+    #[test]
+    fn test_get_after_map_range_split() {
+        // [40,60[ is unmapped below 50, then shifted by 2
+        let mut res = get_after_map_range(&Interval::new(40,60), &seed_to_soil());
+        res.sort();
+        assert_eq!(res, vec![Interval::new(40,50), Interval::new(52,62)]);
+    }
+
+    #[test]
+    fn test_get_after_map_range_two_segments() {
+        // [95,100[ crosses from the second segment into the first one
+        let mut res = get_after_map_range(&Interval::new(95,100), &seed_to_soil());
+        res.sort();
+        assert_eq!(res, vec![Interval::new(50,52), Interval::new(97,100)]);
+    }
+
+    fuzz_parse!(Day05);
+}
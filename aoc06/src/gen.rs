@@ -0,0 +1,47 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day06;
+
+fn concat(numbers: &Vec<i64>) -> i64 {
+    numbers.iter().map(|n| n.to_string()).collect::<String>().parse().unwrap()
+}
+
+fn numbers_line(label: &str, numbers: &Vec<i64>) -> String {
+    format!("{:<9}{}", label, numbers.iter().map(|n| format!("{:>7}", n)).collect::<String>())
+}
+
+impl Generator for Day06 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Every race can be won, including the big one read with the spaces removed.
+        Its time is kept small enough for win_count's floats to stay exact.
+        */
+        let race_count = size.clamp(1, 4);
+        loop {
+            let times: Vec<i64> = (0..race_count).map(|_| rng.range(7, 99)).collect();
+            // the best distance of a race is floor(T²/4)
+            let distances: Vec<i64> = times.iter().map(|t| rng.range(t*t/8, t*t/4 - 1)).collect();
+
+            let (bigtime, bigdist) = (concat(&times), concat(&distances));
+            if bigtime < 60_000_000 && bigdist < bigtime*bigtime/4 {
+                return numbers_line("Time:", &times) + "\n" + &numbers_line("Distance:", &distances) + "\n";
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day06::parse(&Day06::generate(&mut Rng::new(seed), 4)).unwrap();
+            assert!(Day06::part1(&input) > 0);
+            assert!(Day06::part2(&input) > 0);
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::iter::zip;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+#[cfg(feature = "reference")]
+mod reference;
+
+
+fn parse_int_with_whitespaces(line : &Line, s : &str) -> Result<i64, ParseError> {
+    let joined = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    return joined.parse::<i64>().map_err(|_| line.error(s, &format!("expected a number, found `{}`", joined)));
+}
+
+fn parse_both(line : &Line) -> Result<(Vec<i64>, i64), ParseError> {
+    let (_, numbers) = line.split_once(':').ok_or_else(|| line.error_line("expected a `:` before the numbers"))?;
+
+    return Ok((line.intseq(numbers)?, parse_int_with_whitespaces(line, numbers)?));
+}
+
+fn win_count(duration : i64, distance : i64) -> i64 {
+    // with t=time to press, T=duration, D=distance record, d=achieved distance
+    // dist(t) = (T-t)*t = -t² + Tt
+    // dist > D <=> -t² + Tt > D <=> -t² +Tt -D > 0 <=> t² -Tt +D < 0
+    // Delta = T²-4D
+    // R = (T +- sqrt(Delta))/2
+
+    let det = (duration*duration - 4*distance) as f64;
+    let r1 = (duration as f64 - det.sqrt())/2.0;
+    let r2 = (duration as f64 + det.sqrt())/2.0;
+
+    // if R1 < t < R2, then dist(t) > D
+    // t integer => (R1 < t < R2  <=> floor(R1) < t < ceil(R2))
+    // this also holds when R1,R2 are integers (the record is only equalled there)
+    // count = ceil(R2) - floor(R1) -1
+
+    let count = (r2.ceil() - r1.floor()) as i64 -1;
+
+    return count;
+}
+
+pub struct Races {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+    bigtime: i64,
+    bigdist: i64,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Races;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Races, ParseError> {
+        let mut contents_it = lines(contents);
+
+        let time_line = contents_it.next().unwrap();
+        let distance_line = contents_it.next().ok_or_else(|| ParseError::new("expected a distance line after the time line"))?;
+
+        let (times, bigtime) = parse_both(&time_line)?;
+        let (distances, bigdist) = parse_both(&distance_line)?;
+        if times.len() != distances.len() {
+            return Err(distance_line.error_line(&format!("expected {} distances like the times, found {}", times.len(), distances.len())));
+        }
+
+        return Ok(Races{times, distances, bigtime, bigdist});
+    }
+
+    fn part1(races: &Races) -> i64 {
+        let mut res: i64 = 1;
+
+        for (duration,distance) in zip(&races.times,&races.distances) {
+            res *= win_count(*duration, *distance);
+        }
+
+        return res;
+    }
+
+    fn part2(races: &Races) -> i64 {
+        return win_count(races.bigtime, races.bigdist);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day06::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+        assert_eq!(Day06::part1(&input), 288);
+        assert_eq!(Day06::part2(&input), 71503);
+    }
+
+    #[test]
+    fn test_win_count() {
+        assert_eq!(win_count(7, 9), 4);
+        assert_eq!(win_count(30, 200), 9);
+    }
+
+    fuzz_parse!(Day06);
+}
@@ -0,0 +1,33 @@
+use std::iter::zip;
+use aoc_common::reference::Reference;
+use crate::{Day06, Races};
+
+fn naive_win_count(duration: i64, distance: i64) -> i64 {
+    // try every time to press
+    (0..=duration).filter(|t| (duration - t)*t > distance).count() as i64
+}
+
+impl Reference for Day06 {
+    fn reference_part1(races: &Races) -> i64 {
+        zip(&races.times, &races.distances).map(|(duration, distance)| naive_win_count(*duration, *distance)).product()
+    }
+
+    fn reference_part2(races: &Races) -> i64 {
+        naive_win_count(races.bigtime, races.bigdist)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::reference::first_divergence;
+
+    #[test]
+    fn test_matches_reference() {
+        // two races, the big one stays under 10000
+        if let Some(divergence) = first_divergence::<Day06>(0..50, 2) {
+            panic!("{}", divergence);
+        }
+    }
+}
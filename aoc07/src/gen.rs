@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day07;
+
+const CARDS: &str = "AKQJT98765432";
+
+impl Generator for Day07 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Distinct hands, as equal hands with different bids would have no defined order.
+        Bids get smaller for big sizes so that the winnings still fit in an i32.
+        */
+        let count = size.clamp(1, 100_000);
+        let max_bid = (2_000_000_000 / (count*count)).clamp(1, 1000) as i64;
+        let cards: Vec<char> = CARDS.chars().collect();
+
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut res = String::new();
+        while seen.len() < count {
+            // drawing from a few card values gives every kind of hand
+            let mut values = cards.clone();
+            rng.shuffle(&mut values);
+            let values = &values[..rng.range(1, 5) as usize];
+            let hand: String = (0..5).map(|_| *rng.choose(values)).collect();
+            if seen.insert(hand.clone()) {
+                res += &format!("{} {}\n", hand, rng.range(1, max_bid));
+            }
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day07::parse(&Day07::generate(&mut Rng::new(seed), 50)).unwrap();
+            assert_eq!(input.iter().map(|(hand, _)| hand).collect::<HashSet<_>>().len(), 50);
+            Day07::part1(&input);
+            Day07::part2(&input);
+        }
+    }
+}
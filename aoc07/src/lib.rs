@@ -0,0 +1,234 @@
+use std::cmp::Ordering;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::lines;
+
+mod gen;
+
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum HandType {
+    FiveKind = 1,
+    FourKind = 2,
+    FullHouse = 3,
+    ThreeKind = 4,
+    TwoPair = 5,
+    OnePair = 6,
+    HighCard = 7,
+}
+
+#[derive(Eq, Copy, Clone)]
+struct Hand<'a> {
+    htype : HandType,
+    score : i32,
+    _source : &'a str,
+}
+
+impl Ord for Hand<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.htype as isize).cmp(&(other.htype as isize))
+            .then( self.score.cmp(&other.score) )
+    }
+}
+
+impl PartialOrd for Hand<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.htype == other.htype && self.score == other.score
+    }
+}
+
+fn card_score(c : &char, use_jokers: bool) -> i32 {
+    if !use_jokers {
+        return vec![
+            'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2'
+        ].iter().position(|x| x==c).unwrap().try_into().unwrap();
+    } else {
+        return vec![
+            'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J'
+        ].iter().position(|x| x==c).unwrap().try_into().unwrap();
+    }
+}
+
+fn to_counted(s : &str, use_jokers : bool) -> (Vec<(char,i32)>, i32) {
+    let mut res: Vec<(char,i32)> = vec![];
+    let mut joker_count : i32 = 0;
+
+    for c in s.chars() {
+        if use_jokers && c == 'J' {
+            joker_count += 1;
+        } else {
+            let mut added = false;
+            for (rchar, rcount) in res.iter_mut() {
+                if *rchar == c {
+                    *rcount += 1;
+                    added = true;
+                }
+            }
+            if !added {
+                res.push( (c,1) );
+            }
+        }
+    }
+
+    res.sort_by(|&a,&b| a.1.cmp(&b.1).reverse().then( card_score(&a.0, use_jokers).cmp( &card_score(&b.0, use_jokers) ) ) );
+
+    return (res, joker_count);
+}
+
+fn build_hand(hand_str : &str, use_jokers : bool) -> Hand<'_> {
+    let (cards, joker_count) = to_counted(hand_str, use_jokers);
+
+    /*
+    // scoring like poker (not used)
+    let mut score = 0;
+    for (face, _) in cards.iter() {
+        score = 20*score + card_score(&face);
+    }
+    */
+    let mut score = 0;
+    for face in hand_str.chars() {
+        score = 20*score + card_score(&face, use_jokers);
+    }
+
+    if joker_count == 5 {
+        return Hand {
+            htype: HandType::FiveKind,
+            score: score,
+            _source: hand_str
+        }
+    }
+
+    let first_count = cards.get(0).unwrap().1;
+
+    if first_count+joker_count == 5 {
+        return Hand {
+            htype: HandType::FiveKind,
+            score: score,
+            _source: hand_str
+        }
+    }
+    if first_count+joker_count == 4 {
+        return Hand {
+            htype: HandType::FourKind,
+            score: score,
+            _source: hand_str
+        }
+    }
+
+    let sec_count = cards.get(1).unwrap().1;
+    if first_count+joker_count == 3 && sec_count == 2{
+        return Hand {
+            htype: HandType::FullHouse,
+            score: score,
+            _source: hand_str
+        }
+    }
+    if first_count+joker_count == 3 && sec_count == 1{
+        return Hand {
+            htype: HandType::ThreeKind,
+            score: score,
+            _source: hand_str
+        }
+    }
+    if first_count == 2 && sec_count+joker_count == 2{
+        return Hand {
+            htype: HandType::TwoPair,
+            score: score,
+            _source: hand_str
+        }
+    }
+    if first_count+joker_count == 2 && sec_count == 1{
+        return Hand {
+            htype: HandType::OnePair,
+            score: score,
+            _source: hand_str
+        }
+    }
+
+    return Hand {
+        htype: HandType::HighCard,
+        score: score,
+        _source: hand_str
+    }
+}
+
+fn total_winnings(bids: &Vec<(String,i32)>, use_jokers: bool) -> i32 {
+    let mut all_bids : Vec<(Hand,i32)> = bids.iter()
+        .map(|(hand_str,bid)| (build_hand(hand_str, use_jokers),*bid))
+        .collect();
+
+    all_bids.sort_by(|&a,&b| a.0.cmp(&b.0).reverse());
+
+    let mut res = 0;
+    for (i, (_hand,bid)) in all_bids.iter().enumerate() {
+        //println!("Ranked {} is hand {} with bid {}", i+1, hand.source, bid);
+        res += (i as i32 +1) * bid;
+    }
+    return res;
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<(String,i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(contents: &str) -> Result<Vec<(String,i32)>, ParseError> {
+        let mut bids : Vec<(String,i32)> = vec![];
+
+        for line in lines(contents) {
+            if line.len() < 2 {
+                continue;
+            }
+
+            let (hand_str, bid_str) = line.split_once(' ').ok_or_else(|| line.error_line("expected `<hand> <bid>`"))?;
+            if hand_str.chars().count() != 5 || !hand_str.chars().all(|c| "AKQJT98765432".contains(c)) {
+                return Err(line.error(hand_str, "expected a hand of 5 cards among `AKQJT98765432`"));
+            }
+            let bid = line.number::<i32>(bid_str)?;
+
+            bids.push( (hand_str.to_string(),bid) );
+        }
+
+        return Ok(bids);
+    }
+
+    fn part1(bids: &Vec<(String,i32)>) -> i32 {
+        total_winnings(bids, false)
+    }
+
+    fn part2(bids: &Vec<(String,i32)>) -> i32 {
+        total_winnings(bids, true)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+    use aoc_common::parse::normalize;
+
+    #[test]
+    fn test_example() {
+        let input = Day07::parse("32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483\n").unwrap();
+        assert_eq!(Day07::part1(&input), 6440);
+        assert_eq!(Day07::part2(&input), 5905);
+    }
+
+    #[test]
+    fn test_example_crlf() {
+        let contents = normalize("\u{feff}32T3K 765\r\nT55J5 684\r\nKK677 28\r\nKTJJT 220\r\nQQQJA 483 \r\n\r\n");
+        let input = Day07::parse(&contents).unwrap();
+        assert_eq!(Day07::part1(&input), 6440);
+    }
+
+    fuzz_parse!(Day07);
+}
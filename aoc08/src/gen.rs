@@ -0,0 +1,94 @@
+use std::collections::HashSet;
+use aoc_common::Generator;
+use aoc_common::gen::random_word;
+use aoc_common::rng::Rng;
+use crate::Day08;
+
+const PRIMES: [usize; 16] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59];
+const MAX_NODES: usize = 12_000;
+
+fn new_name(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    // never ending with A or Z, only the starts and the ends do
+    loop {
+        let name = random_word(rng, 2, "ABCDEFGHIJKLMNOPQRSTUVWXYZ") + &random_word(rng, 1, "BCDEFGHIJKLMNOPQRSTUVWXY");
+        if used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+impl Generator for Day08 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Like the puzzle, every ghost runs in its own loop of nodes of L*q steps, with L the
+        length of the directions and q a prime, and its Z node as the last node of the loop.
+        The A node leads where the Z node does, so the Z node is met every L*q steps,
+        and is the only Z node of the loop, as the solver expects. AAA and ZZZ are the first ghost.
+        */
+        // small sizes keep to the small primes, for the ghosts to meet early enough to be simulated
+        let mut primes = PRIMES[..size.clamp(5, PRIMES.len())].to_vec();
+        rng.shuffle(&mut primes);
+        let ghost_count = rng.range(2, 5) as usize;
+        let primes = &primes[..ghost_count];
+        let dir_len = size.clamp(2, MAX_NODES / (primes.iter().sum::<usize>() + 1));
+        let sides: String = (0..dir_len).map(|_| if rng.chance(0.5) { 'L' } else { 'R' }).collect();
+
+        let mut used: HashSet<String> = HashSet::new();
+        let mut nodes: Vec<(String, String, String)> = vec![];
+        for (ghost, q) in primes.iter().enumerate() {
+            let (start, end) = if ghost == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                let base = random_word(rng, 2, "BCDEFGHIJKLMNOPQRSTUVWXY");
+                (base.clone() + "A", base + "Z")
+            };
+            if used.contains(&start) || used.contains(&end) {
+                continue;
+            }
+            used.insert(start.clone());
+            used.insert(end.clone());
+
+            // the loop: end, then the nodes reached after 1, 2, ... steps
+            let mut names = vec![end.clone()];
+            for _ in 1..(dir_len * q) {
+                names.push(new_name(rng, &mut used));
+            }
+            for (i, name) in names.iter().enumerate() {
+                // node i is left on the step i, the other side is never taken
+                let next = names[(i+1) % names.len()].clone();
+                let other = rng.choose(&names).clone();
+                let (left, right) = if sides.as_bytes()[i % dir_len] == b'L' { (next, other) } else { (other, next) };
+                if i == 0 {
+                    nodes.push( (start.clone(), left.clone(), right.clone()) );
+                }
+                nodes.push( (name.clone(), left, right) );
+            }
+        }
+
+        rng.shuffle(&mut nodes);
+        let mut res = sides + "\n\n";
+        for (name, left, right) in nodes {
+            res += &format!("{} = ({}, {})\n", name, left, right);
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{MaybeAnswer, Solution};
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day08::parse(&Day08::generate(&mut Rng::new(seed), 10)).unwrap();
+            match Day08::part1(&input) {
+                MaybeAnswer::Answer(first_loop) => assert_eq!(first_loop % input.sides.len(), 0),
+                MaybeAnswer::NoAnswer(reason) => panic!("no answer for seed {}: {}", seed, reason),
+            }
+            Day08::part2(&input);
+        }
+    }
+}
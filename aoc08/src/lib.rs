@@ -0,0 +1,341 @@
+use std::cmp::max;
+use std::collections::HashMap;
+use num_bigint::BigInt;
+use num_traits::identities::One;
+use aoc_common::cycle::find_cycle_brent;
+use aoc_common::numtheory::crt_pair;
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+#[cfg(feature = "reference")]
+mod reference;
+
+
+fn to_node_id(s : &str) -> i64 {
+    // two decimal digits per character: letters from 1 for 'A' to 26 for 'Z', then digits from 27
+    // nothing maps to 0, so that "AA" and "AAA" differ
+    let mut res : i64 = 0;
+    for c in s.chars() {
+        match c {
+            'A'..='Z' => {
+                res *= 100;
+                res += 1 + (c as i64) - ('A' as i64);
+            },
+            '0'..='9' => {
+                res *= 100;
+                res += 27 + (c as i64) - ('0' as i64);
+            },
+            _ => {}
+        }
+    }
+    return res;
+}
+
+fn ends_with(node: i64, c: char) -> bool {
+    return node%100 == to_node_id(&c.to_string());
+}
+
+fn parse_node_id(line: &Line, s: &str) -> Result<i64, ParseError> {
+    // a node name padded with spaces or parentheses, longer names would overflow the id
+    let name = s.trim_matches(|c: char| c == ' ' || c == '(' || c == ')');
+    if name.len() == 0 || name.len() > 3 || !name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+        let token = if name.len() > 0 { name } else { s };
+        return Err(line.error(token, &format!("expected a node name of up to 3 capital letters or digits, found `{}`", name)));
+    }
+    return Ok(to_node_id(name));
+}
+
+fn side_to_bools(s : &str) -> Vec<bool> {
+    return s.chars().map(|c| c=='R').collect();
+}
+
+fn merge_loops(loop1: (BigInt,BigInt), loop2: (BigInt,BigInt)) -> Option<(BigInt,BigInt)> {
+    // S = A + ka*A' = B+kb*B', None when both loops never meet
+    let (start1, size1) = loop1;
+    let (start2, size2) = loop2;
+
+    let (first, size) = crt_pair(&(start1.clone(), size1), &(start2.clone(), size2))?;
+
+    // the first meeting once both loops have started
+    let begin = max(start1, start2);
+    let start = first.clone() + (begin - first + size.clone() - BigInt::one()) / size.clone() * size.clone();
+
+    return Some((start, size));
+}
+
+fn tuple_indexed(pair : &(i64,i64), idx : bool) -> i64 {
+    if idx {
+        return pair.1;
+    } else {
+        return pair.0;
+    }
+}
+
+fn get_loop_description(map: &HashMap<i64,(i64,i64)>, dir: &Vec<bool>, start: i64) -> (i64, Vec<i64>, Vec<i64>) {
+    /*
+    Since map and dir are finite, the path will end up in a loop,
+    of maximum size |map|*|dir| = 702*271 = 190242 and minimum size |dir| = 271
+
+    This loop will have a size S, and a number of 'Z' nodes
+    The length to reach the Z nodes in that loop is always C_i + k*S
+
+    This function returns S, followed by all C_i, followed by all indexes of Z nodes before the loop
+    */
+    let step = |&(node, dir_idx): &(i64,usize)| {
+        // follow instructions, to the next cell & next index
+        let next = tuple_indexed(map.get(&node).unwrap(), *dir.get(dir_idx).unwrap());
+        (next, (dir_idx+1)%dir.len())
+    };
+    // the states are small but many, no need to store them all
+    let cycle = find_cycle_brent(&(start,0), step);
+
+    // walk through the path before the loop and the loop once
+    let mut current = (start,0);
+    let mut z_indexes: Vec<i64> = vec![];
+    for path_length in 0..cycle.prefix+cycle.period {
+        if ends_with(current.0, 'Z') {
+            z_indexes.push(path_length as i64);
+        }
+        current = step(&current);
+    }
+
+    let loop_path_length = cycle.prefix as i64; // size of path before the loop
+    let size = cycle.period as i64; // size of the loop
+
+    let z_before_loop = z_indexes.iter().filter(|&len| len<&loop_path_length).copied().collect();
+    let z_in_loop = z_indexes.iter().filter(|&len| len>=&loop_path_length).copied().rev().collect();
+
+    return (size, z_in_loop, z_before_loop);
+}
+
+fn steps_to_end(network: &Network) -> Result<usize, String> {
+    /*
+    The walk from AAA loops once a (node, instruction) state comes back:
+    ZZZ is reached in the path before the loop or the loop once, or never.
+    */
+    let (start, end) = (to_node_id("AAA"), to_node_id("ZZZ"));
+    if !network.directions.contains_key(&start) {
+        return Err("there is no node AAA to start from".to_string());
+    }
+    let step = |&(node, dir_idx): &(i64,usize)| {
+        let next = tuple_indexed(&network.directions[&node], network.sides[dir_idx]);
+        (next, (dir_idx+1)%network.sides.len())
+    };
+    let cycle = find_cycle_brent(&(start,0), step);
+
+    let mut current = (start,0);
+    for steps in 0..cycle.prefix+cycle.period {
+        if current.0 == end {
+            return Ok(steps);
+        }
+        current = step(&current);
+    }
+    return Err("ZZZ is never reached from AAA".to_string());
+}
+
+fn ghosts_meeting(network: &Network) -> Result<BigInt, String> {
+    /*
+    Each ghost is on a Z node at the steps before its loop, and at C_i + k*S for the Z nodes of its loop.
+    A step where every ghost is on a Z node before some ghost loops comes first; otherwise, the ghosts meet
+    on one combination of the Z nodes of their loops (a single one on input data).
+    */
+    let mut start_nodes: Vec<i64> = network.directions.keys().filter(|k| ends_with(**k, 'A')).copied().collect();
+    start_nodes.sort();
+    if start_nodes.len() == 0 {
+        return Err("there are no nodes ending with A to start from".to_string());
+    }
+    let descs: Vec<(i64, Vec<i64>, Vec<i64>)> = start_nodes.iter().map(|start| get_loop_description(&network.directions, &network.sides, *start)).collect();
+
+    let on_end = |(size, z_in_loop, z_before_loop): &(i64, Vec<i64>, Vec<i64>), steps: i64| {
+        z_before_loop.contains(&steps) || z_in_loop.iter().any(|c| steps >= *c && (steps - c) % size == 0)
+    };
+    let early = descs.iter().flat_map(|desc| desc.2.iter().copied()).filter(|steps| descs.iter().all(|desc| on_end(desc, *steps))).min();
+    if let Some(steps) = early {
+        return Ok(BigInt::from(steps));
+    }
+
+    let mut loops: Vec<(BigInt,BigInt)> = descs[0].1.iter().map(|c| (BigInt::from(*c), BigInt::from(descs[0].0))).collect(); // (C,S) to have C + k*S
+    for (size, z_in_loop, _) in descs.iter().skip(1) {
+        loops = loops.iter()
+            .flat_map(|curloop| z_in_loop.iter().filter_map(|c| merge_loops(curloop.clone(), (BigInt::from(*c), BigInt::from(*size)))))
+            .collect();
+    }
+
+    return loops.into_iter().map(|(start, _)| start).min().ok_or("the ghosts never reach their ends together".to_string());
+}
+
+pub struct Network {
+    sides: Vec<bool>,
+    directions: HashMap<i64,(i64,i64)>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Network;
+    type Answer1 = MaybeAnswer<usize>;
+    type Answer2 = MaybeAnswer<BigInt>;
+
+    fn parse(contents: &str) -> Result<Network, ParseError> {
+        let mut contents_it = lines(contents);
+
+        let sides_line = contents_it.next().unwrap();
+        if sides_line.len() == 0 || !sides_line.chars().all(|c| c == 'L' || c == 'R') {
+            return Err(sides_line.error_line("expected a sequence of `L` and `R` directions"));
+        }
+        let sides = side_to_bools( &sides_line );
+        contents_it.next();
+
+        let mut directions: HashMap<i64,(i64,i64)> = HashMap::new();
+        let mut destinations: Vec<(Line, &str, i64)> = vec![]; // checked once every node is known
+        let mut defined: HashMap<i64, Line> = HashMap::new();
+        for line in contents_it {
+            if line.len() < 2 {
+                continue;
+            }
+            let (source_str, dest_str) = line.text.split_once('=').ok_or_else(|| line.error_line("expected `<node> = (<left>, <right>)`"))?;
+            let source = parse_node_id(&line, source_str)?;
+            if let Some(first) = defined.get(&source) {
+                let name = source_str.trim();
+                return Err(line.error(name, &format!("node `{}` is already defined on line {}", name, first.number)));
+            }
+            defined.insert(source, line);
+
+            let (left_str, right_str) = dest_str.split_once(',').ok_or_else(|| line.error(dest_str, "expected `(<left>, <right>)`"))?;
+            let dir_left = parse_node_id(&line, left_str)?;
+            let dir_right = parse_node_id(&line, right_str)?;
+
+            destinations.push( (line, left_str, dir_left) );
+            destinations.push( (line, right_str, dir_right) );
+            directions.insert(source, (dir_left,dir_right));
+        }
+
+        for (line, node_str, node) in destinations {
+            if !directions.contains_key(&node) {
+                let name = node_str.trim_matches(|c: char| c == ' ' || c == '(' || c == ')');
+                return Err(line.error(name, &format!("node `{}` is never defined", name)));
+            }
+        }
+
+        //dbg!(&sides);
+        //dbg!(&directions);
+
+        return Ok(Network{sides, directions});
+    }
+
+    fn part1(network: &Network) -> MaybeAnswer<usize> {
+        steps_to_end(network).into()
+    }
+
+    fn part2(network: &Network) -> MaybeAnswer<BigInt> {
+        ghosts_meeting(network).into()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        // the published part 2 example, with a loop from AAA to ZZZ for part 1
+        let input = Day08::parse("LR\n\nAAA = (BBB, XXX)\nBBB = (XXX, ZZZ)\nZZZ = (BBB, XXX)\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)").unwrap();
+        assert_eq!(steps_to_end(&input), Ok(2));
+        assert_eq!(ghosts_meeting(&input), Ok(BigInt::from(6)));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        // the published part 2 example has no AAA
+        let input = Day08::parse("LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)").unwrap();
+        assert!(steps_to_end(&input).is_err());
+        assert_eq!(Day08::part2(&input).to_string(), "6");
+        // ZZZ out of reach, and no ghost
+        let input = Day08::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(Day08::part1(&input).to_string(), "no answer (ZZZ is never reached from AAA)");
+        assert!(ghosts_meeting(&input).is_err());
+        let input = Day08::parse("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert!(ghosts_meeting(&input).is_err());
+        // ghosts on Z nodes at odd steps only, and at even steps only
+        let input = Day08::parse("L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n").unwrap();
+        assert_eq!(Day08::part2(&input).to_string(), "no answer (the ghosts never reach their ends together)");
+    }
+
+    #[test]
+    fn test_ghosts_meeting_before_loops() {
+        let input = Day08::parse("L\n\n11A = (11Z, 11Z)\n11Z = (XXX, XXX)\n22A = (22Z, 22Z)\n22Z = (22Z, 22Z)\nXXX = (XXX, XXX)\n").unwrap();
+        assert_eq!(ghosts_meeting(&input), Ok(BigInt::from(1)));
+    }
+
+    #[test]
+    fn test_example_part1_repeated() {
+        let input = Day08::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(steps_to_end(&input), Ok(6));
+    }
+
+    #[test]
+    fn test_merge_loops_coprime() {
+        // x = 2 mod 3 and x = 3 mod 5
+        let (start, size) = merge_loops((BigInt::from(2), BigInt::from(3)), (BigInt::from(3), BigInt::from(5))).unwrap();
+        assert_eq!(size, BigInt::from(15));
+        assert_eq!(start, BigInt::from(8));
+    }
+
+    #[test]
+    fn test_merge_loops_common_factor() {
+        // x = 1 mod 6 and x = 3 mod 4
+        let (start, size) = merge_loops((BigInt::from(1), BigInt::from(6)), (BigInt::from(3), BigInt::from(4))).unwrap();
+        assert_eq!(size, BigInt::from(12));
+        assert_eq!(start, BigInt::from(7));
+    }
+
+    #[test]
+    fn test_merge_loops_divisible() {
+        // x = 5 mod 4 and x = 1 mod 2, once both have started
+        let (start, size) = merge_loops((BigInt::from(5), BigInt::from(4)), (BigInt::from(1), BigInt::from(2))).unwrap();
+        assert_eq!(size, BigInt::from(4));
+        assert_eq!(start, BigInt::from(5));
+    }
+
+    #[test]
+    fn test_merge_loops_never_meet() {
+        // x = 1 mod 6 and x = 2 mod 4
+        assert_eq!(merge_loops((BigInt::from(1), BigInt::from(6)), (BigInt::from(2), BigInt::from(4))), None);
+    }
+
+    #[test]
+    fn test_parse_long_node_name() {
+        let e = Day08::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAAAAAAAAAAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (4, 8, 12));
+    }
+
+    #[test]
+    fn test_node_names_with_digits() {
+        assert_ne!(to_node_id("11A"), to_node_id("22A"));
+        assert_ne!(to_node_id("1AZ"), to_node_id("AZ"));
+        assert!(ends_with(to_node_id("11Z"), 'Z') && ends_with(to_node_id("11A"), 'A'));
+        let e = Day08::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (aaa, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (4, 8, 3));
+    }
+
+    #[test]
+    fn test_node_names_of_any_length() {
+        assert_ne!(to_node_id("AA"), to_node_id("AAA"));
+        assert_ne!(to_node_id("BA"), to_node_id("ABA"));
+        let input = Day08::parse("L\n\nAAA = (AA, AA)\nAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(steps_to_end(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_parse_twice_defined() {
+        let e = Day08::parse("LR\n\nAAA = (BBB, ZZZ)\nBBB = (ZZZ, ZZZ)\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (5, 1, 3));
+        assert!(e.message.contains("line 3"));
+    }
+
+    fuzz_parse!(Day08);
+}
@@ -0,0 +1,45 @@
+use num_bigint::BigInt;
+use aoc_common::MaybeAnswer;
+use aoc_common::reference::Reference;
+use crate::{Day08, Network, ends_with, to_node_id, tuple_indexed};
+
+impl Reference for Day08 {
+    fn reference_part1(network: &Network) -> MaybeAnswer<usize> {
+        let mut current = to_node_id("AAA");
+        let mut steps = 0;
+        while current != to_node_id("ZZZ") {
+            current = tuple_indexed(&network.directions[&current], network.sides[steps % network.sides.len()]);
+            steps += 1;
+        }
+        return MaybeAnswer::Answer(steps);
+    }
+
+    fn reference_part2(network: &Network) -> MaybeAnswer<BigInt> {
+        // every ghost walks at once, until they all stand on a Z node
+        let mut ghosts: Vec<i64> = network.directions.keys().filter(|k| ends_with(**k, 'A')).copied().collect();
+        let mut steps: usize = 0;
+        while !ghosts.iter().all(|g| ends_with(*g, 'Z')) {
+            let side = network.sides[steps % network.sides.len()];
+            for g in ghosts.iter_mut() {
+                *g = tuple_indexed(&network.directions[g], side);
+            }
+            steps += 1;
+        }
+        return MaybeAnswer::Answer(BigInt::from(steps));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::reference::first_divergence;
+
+    #[test]
+    fn test_matches_reference() {
+        // the smallest loops, a few ten thousand steps for all the ghosts to meet
+        if let Some(divergence) = first_divergence::<Day08>(0..20, 2) {
+            panic!("{}", divergence);
+        }
+    }
+}
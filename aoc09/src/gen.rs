@@ -0,0 +1,52 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day09;
+
+fn binomial(n: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+impl Generator for Day09 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Each history is a polynomial of a degree low enough for its differences
+        to reach zeros before running out of values, as next_val expects.
+        Writing it on the binomials C(n,k) keeps every value an integer.
+        */
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let len = rng.range(6, 21);
+            let degree = rng.range(0, (len - 2).min(7));
+            let coefficients: Vec<i64> = (0..=degree).map(|k| if k == 0 { rng.range(-20, 20) } else { rng.range(-9, 9) }).collect();
+            let values: Vec<String> = (0..len)
+                .map(|n| coefficients.iter().enumerate().map(|(k, c)| c * binomial(n, k as i64)).sum::<i64>().to_string())
+                .collect();
+            res += &values.join(" ");
+            res.push('\n');
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!((0..6).map(|k| binomial(5, k)).collect::<Vec<i64>>(), vec![1, 5, 10, 10, 5, 1]);
+        assert_eq!(binomial(3, 5), 0);
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day09::parse(&Day09::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.len(), 20);
+            Day09::part1(&input);
+            Day09::part2(&input);
+        }
+    }
+}
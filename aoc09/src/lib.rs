@@ -0,0 +1,77 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::lines;
+
+mod gen;
+
+
+fn next_val(v : &Vec<i64>) -> i64 {
+    let mut diff: Vec<i64> = vec![];
+    let mut prev: Option<i64> = None;
+    for num in v {
+        if prev.is_some() {
+            diff.push( *num - prev.unwrap() );
+        }
+        prev = Some(*num);
+    }
+
+    if diff.iter().all(|&x| x==0) {
+        return prev.unwrap();
+    } else {
+        let additional = next_val(&diff);
+        return prev.unwrap() + additional;
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        let mut sequences : Vec<Vec<i64>> = vec![];
+
+        for line in lines(contents) {
+            if line.len() < 2 {
+                continue;
+            }
+            sequences.push(line.intseq(&line)?);
+        }
+
+        return Ok(sequences);
+    }
+
+    fn part1(sequences: &Vec<Vec<i64>>) -> i64 {
+        let mut res : i64 = 0;
+        for seq in sequences {
+            res += next_val(seq);
+        }
+        return res;
+    }
+
+    fn part2(sequences: &Vec<Vec<i64>>) -> i64 {
+        let mut res2 : i64 = 0;
+        for seq in sequences {
+            res2 += next_val(&seq.iter().rev().copied().collect());
+        }
+        return res2;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day09::parse("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n").unwrap();
+        assert_eq!(Day09::part1(&input), 114);
+        assert_eq!(Day09::part2(&input), 2);
+    }
+
+    fuzz_parse!(Day09);
+}
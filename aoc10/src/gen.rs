@@ -0,0 +1,63 @@
+use aoc_common::Generator;
+use aoc_common::gen::random_loop;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::{get_pipe, Day10};
+
+const JUNK: &str = "-|JL7F...";
+
+fn direction_to(from: Point, to: Point) -> Direction {
+    *Direction::ALL.iter().find(|dir| from.step(**dir) == to).unwrap()
+}
+
+impl Generator for Day10 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        A single loop drawn with scaled-up cells so that it encloses tiles, among junk pipes.
+        The tiles next to S which are not on the loop are ground, so that S has only one way in and out.
+        */
+        let coarse = (size/4).max(1);
+        let corners = random_loop(rng, coarse, coarse, 2);
+        let margin = rng.range(0, 2);
+        let side = ((2*coarse - 1)*2 + 1) as i64 + 2*margin;
+
+        let junk: Vec<char> = JUNK.chars().collect();
+        let mut map: Grid<char> = Grid::from_fn(side as usize, side as usize, |_, _| *rng.choose(&junk));
+        let at = |p: Point| Point::new(p.row + margin, p.col + margin);
+        for (i, p) in corners.iter().enumerate() {
+            let prev = corners[(i + corners.len() - 1) % corners.len()];
+            let next = corners[(i + 1) % corners.len()];
+            map[at(*p)] = get_pipe(&direction_to(*p, prev), &direction_to(*p, next)).to_char();
+        }
+
+        let start = rng.below(corners.len());
+        let prev = corners[(start + corners.len() - 1) % corners.len()];
+        let next = corners[(start + 1) % corners.len()];
+        for dir in Direction::ALL {
+            let neighbour = corners[start].step(dir);
+            if neighbour != prev && neighbour != next && map.bounds().contains(at(neighbour)) {
+                map[at(neighbour)] = '.';
+            }
+        }
+        map[at(corners[start])] = 'S';
+
+        return map.display(|c| *c).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day10::parse(&Day10::generate(&mut Rng::new(seed), 12)).unwrap();
+            assert!(Day10::part1(&input) >= 2);
+            Day10::part2(&input);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{grid_lines, Line};
+use aoc_common::pipe::PipeType;
+
+mod gen;
+mod render;
+
+
+fn convert_pipe(dir: &Direction, pipe: &PipeType) -> Option<Direction> {
+    return match pipe {
+        PipeType::None => None,
+        PipeType::Horizontal => {
+            match dir {
+                Direction::Right => Some(Direction::Right),
+                Direction::Left => Some(Direction::Left),
+                _ => None,
+            }
+        },
+        PipeType::Vertical => {
+            match dir {
+                Direction::Top => Some(Direction::Top),
+                Direction::Bottom => Some(Direction::Bottom),
+                _ => None,
+            }
+        },
+        PipeType::BendUL => {
+            match dir {
+                Direction::Right => Some(Direction::Top),
+                Direction::Bottom => Some(Direction::Left),
+                _ => None,
+            }
+        },
+        PipeType::BendDL => {
+            match dir {
+                Direction::Right => Some(Direction::Bottom),
+                Direction::Top => Some(Direction::Left),
+                _ => None,
+            }
+        },
+        PipeType::BendUR => {
+            match dir {
+                Direction::Left => Some(Direction::Top),
+                Direction::Bottom => Some(Direction::Right),
+                _ => None,
+            }
+        },
+        PipeType::BendDR => {
+            match dir {
+                Direction::Left => Some(Direction::Bottom),
+                Direction::Top => Some(Direction::Right),
+                _ => None,
+            }
+        },
+    }
+}
+
+fn get_pipe(dir1: &Direction, dir2: &Direction) -> PipeType {
+    // get pipe from two outgoing directions
+    return match (dir1,dir2) {
+        (Direction::Top, Direction::Bottom) | (Direction::Bottom, Direction::Top) => PipeType::Vertical,
+        (Direction::Left, Direction::Right) | (Direction::Right, Direction::Left) => PipeType::Horizontal,
+        (Direction::Top, Direction::Right) | (Direction::Right, Direction::Top) => PipeType::BendUR,
+        (Direction::Top, Direction::Left) | (Direction::Left, Direction::Top) => PipeType::BendUL,
+        (Direction::Bottom, Direction::Right) | (Direction::Right, Direction::Bottom) => PipeType::BendDR,
+        (Direction::Bottom, Direction::Left) | (Direction::Left, Direction::Bottom) => PipeType::BendDL,
+        _ => PipeType::None,
+    }
+}
+
+pub struct PipeMaze {
+    map: Grid<PipeType>,
+    search_heads: Vec<(Point,Direction)>,
+}
+
+fn find_loop(maze: &PipeMaze) -> (Grid<PipeType>, Vec<Point>) {
+    // follow the pipes from the start, returns the map with the start pipe replaced and the loop tiles
+    let mut map = maze.map.clone();
+    let bounds = map.bounds();
+    let search_heads = &maze.search_heads;
+
+    let mut loop_tiles: Vec<Point> = vec![];
+    for (start, hd) in search_heads.iter() {
+        let (mut cur, mut cd) = (*start, *hd);
+        loop_tiles = vec![*start];
+        let mut found = false;
+
+        while loop_tiles.len() ==1 || cur != *start {
+            loop_tiles.push(cur);
+
+            let next = match cur.checked_step(cd, &bounds) {
+                Some(next) => next,
+                None => {
+                    //println!("Search head ended on border {:?} {:?}", cur, cd);
+                    break;
+                }
+            };
+
+            if next == *start {
+                let start_pipe = get_pipe(hd, &cd.reverse());
+                map[*start] = start_pipe;
+                //println!("Overwritting start pipe {}", start_pipe as i32);
+                found = true;
+                break;
+            }
+
+            let nd = convert_pipe(&cd, &map[next]);
+
+            if nd.is_none() {
+                //println!("Search head ended on nothing");
+                break;
+            }
+
+            (cur, cd) = (next, nd.unwrap());
+        }
+
+        if found {
+            break;
+        }
+    }
+
+    return (map, loop_tiles);
+}
+
+fn enclosed_tiles(map: &Grid<PipeType>, walls: &Grid<bool>) -> Grid<bool> {
+    // the tiles inside the loop, scanning each row and tracking the corners above and below
+    let mut inside = Grid::new(map.height(), map.width(), false);
+    for (i,line) in walls.rows().enumerate() {
+        let mut in_top = false; // is the top right corner in area
+        let mut in_bot = false; // is the bottom right corner in area
+        for (j,is_wall) in line.iter().enumerate() {
+            if *is_wall {
+                let pipe = map[(i, j)];
+                if pipe == PipeType::Vertical {
+                    in_top = !in_top;
+                    in_bot = !in_bot;
+                } else if pipe == PipeType::Horizontal {
+                    // don't change anything
+                } else if pipe == PipeType::BendUR {
+                    // no wall -> on wall
+                    in_top = !in_top;
+                } else if pipe == PipeType::BendDR {
+                    // no wall -> on wall
+                    in_bot = !in_bot;
+                } else if pipe == PipeType::BendUL {
+                    // on wall -> no wall
+                    in_top = !in_top;
+                } else if pipe == PipeType::BendDL {
+                    // on wall -> no wall
+                    in_bot = !in_bot;
+                }
+            } else {
+                if in_top { // !is_wall -> (in_top == in_bot)
+                    inside[(i, j)] = true;
+                }
+            }
+        }
+    }
+
+    return inside;
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = PipeMaze;
+    type Answer1 = usize;
+    type Answer2 = i32;
+
+    fn parse(contents: &str) -> Result<PipeMaze, ParseError> {
+        let lines: Vec<Line> = grid_lines(contents)?;
+        let map = Grid::from_lines(&lines, |c| if "-|JL7F.S".contains(c) { Some(PipeType::from_char(c)) } else { None })?;
+
+        let mut search_heads: Vec<(Point,Direction)> = vec![];
+        for (i,line) in lines.iter().enumerate() {
+            for (j,(pos,c)) in line.char_indices().enumerate() {
+                if c == 'S' {
+                    if search_heads.len() > 0 {
+                        return Err(line.error(&line.text[pos..(pos+1)], "found a second starting tile `S`"));
+                    }
+                    let start = Point::new(i as i64, j as i64);
+                    search_heads = [Direction::Right, Direction::Top, Direction::Left, Direction::Bottom]
+                        .iter().map(|dir| (start, *dir)).collect();
+                }
+            }
+        }
+
+        if search_heads.len() == 0 {
+            return Err(ParseError::new("no starting tile `S` in the maze"));
+        }
+
+        return Ok(PipeMaze{map, search_heads});
+    }
+
+    fn part1(maze: &PipeMaze) -> usize {
+        let (_, loop_tiles) = find_loop(maze);
+        return loop_tiles.len()/2;
+    }
+
+    fn part2(maze: &PipeMaze) -> i32 {
+        let (map, loop_tiles) = find_loop(maze);
+
+        let mut walls = Grid::new(map.height(), map.width(), false);
+        for tile in loop_tiles.iter() {
+            walls[*tile] = true;
+        }
+
+        return enclosed_tiles(&map, &walls).iter().filter(|(_,inside)| **inside).count() as i32;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+    use aoc_common::parse::normalize;
+
+    #[test]
+    fn test_example_part1() {
+        let input = Day10::parse("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...\n").unwrap();
+        assert_eq!(Day10::part1(&input), 8);
+    }
+
+    #[test]
+    fn test_example_crlf() {
+        let input = Day10::parse(&normalize("..F7.\r\n.FJ|.\r\nSJ.L7\r\n|F--J\r\nLJ...\r\n")).unwrap();
+        assert_eq!(Day10::part1(&input), 8);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let input = Day10::parse("...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........\n").unwrap();
+        assert_eq!(Day10::part2(&input), 4);
+    }
+
+    #[test]
+    fn test_example_part2_squeezed() {
+        let input = Day10::parse("..........\n.S------7.\n.|F----7|.\n.||....||.\n.||....||.\n.|L-7F-J|.\n.|..||..|.\n.L--JL--J.\n..........\n").unwrap();
+        assert_eq!(Day10::part2(&input), 4);
+    }
+
+    fuzz_parse!(Day10);
+}
@@ -0,0 +1,50 @@
+use aoc_common::grid::Grid;
+use aoc_common::pipe::PipeType;
+use aoc_common::render::{Color, Picture, Render};
+use crate::{Day10, PipeMaze, enclosed_tiles, find_loop};
+
+const PIPE: Color = Color::new(90, 90, 90);
+const INSIDE: Color = Color::new(80, 200, 120);
+const LOOP: Color = Color::new(230, 160, 30);
+
+impl Render for Day10 {
+    fn render(maze: &PipeMaze) -> Picture {
+        // the loop over the other pipes, and the tiles it encloses
+        let (map, loop_tiles) = find_loop(maze);
+        let mut walls = Grid::new(map.height(), map.width(), false);
+        for tile in loop_tiles.iter() {
+            walls[*tile] = true;
+        }
+
+        let mut picture = Picture::from_grid(&map, Color::BLACK, |pipe| if *pipe == PipeType::None { Color::BLACK } else { PIPE });
+        for (pos, inside) in enclosed_tiles(&map, &walls).iter() {
+            if *inside {
+                picture.cells[pos] = INSIDE;
+            }
+        }
+
+        // the start is in the tiles twice, the path goes back to it instead
+        let mut path = loop_tiles.clone();
+        path.dedup();
+        path.push(path[0]);
+        picture.add_path(path, LOOP);
+        return picture;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_render() {
+        let input = Day10::parse("...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........\n").unwrap();
+        let picture = Day10::render(&input);
+        assert_eq!(picture.cells.iter().filter(|(_, c)| **c == INSIDE).count(), 4);
+        let (path, _) = &picture.paths[0];
+        assert_eq!(path.len(), 2*Day10::part1(&input) + 1);
+        assert_eq!(path.first(), path.last());
+    }
+}
@@ -0,0 +1,31 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day11;
+
+impl Generator for Day11 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // sparse galaxies, with about a tenth of the rows and columns left empty to be expanded
+        let side = size.max(2);
+        let empty_rows: Vec<bool> = (0..side).map(|_| rng.chance(0.1)).collect();
+        let empty_cols: Vec<bool> = (0..side).map(|_| rng.chance(0.1)).collect();
+        let map = Grid::from_fn(side, side, |row, col| !empty_rows[row] && !empty_cols[col] && rng.chance(0.05));
+        return map.display(|b| if *b { '#' } else { '.' }).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day11::parse(&Day11::generate(&mut Rng::new(seed), 30)).unwrap();
+            // expanding further can only push the galaxies apart
+            assert!(Day11::part1(&input) <= Day11::part2(&input));
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::grid::Grid;
+
+mod gen;
+#[cfg(feature = "reference")]
+mod reference;
+
+
+fn empty_row_indexes(map: &Grid<bool>) -> Vec<usize> {
+    map.rows().enumerate().filter(|(_,r)| r.iter().filter(|&k| *k).count()==0).map(|(i,_)| i).collect()
+}
+
+fn expand_positions(v: &Vec<(usize,usize)>, xgrow: &Vec<usize>, ygrow: &Vec<usize>, amount:usize) -> Vec<(usize,usize)> {
+    // replace cells at indicated indexes by cells of size 'amount'
+    v.iter().map(|(x,y)| (
+        x + xgrow.iter().filter(|&v| v<x).count()*(amount-1),
+        y + ygrow.iter().filter(|&v| v<y).count()*(amount-1),
+    )).collect()
+}
+
+fn get_positions(map: &Grid<bool>) -> Vec<(usize,usize)> {
+    map.iter().filter(|(_,v)| **v).map(|(pos,_)| pos).collect()
+}
+
+fn iter_pairs<T: Copy>(v: Vec<T>) -> Vec<(T,T)> {
+    // [0,1,2] -> (0,1), (0,2), (1,2)
+    return v.iter().enumerate().flat_map(|(i,a)| v[(i+1)..].iter().map(|b| (*a,*b))).collect();
+}
+
+pub struct Universe {
+    galaxies: Vec<(usize,usize)>,
+    xempty: Vec<usize>,
+    yempty: Vec<usize>,
+}
+
+fn total_distance(universe: &Universe, grow: usize) -> isize {
+    let positions = expand_positions(&universe.galaxies, &universe.xempty, &universe.yempty, grow);
+
+    //dbg!(&positions);
+
+    let mut res = 0;
+    for ((ax,ay), (bx,by)) in iter_pairs(positions.iter().map(|(x,y)| (*x as isize, *y as isize)).collect()) {
+        res += (ax-bx).abs() + (ay-by).abs();
+    }
+    return res;
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Universe;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(contents: &str) -> Result<Universe, ParseError> {
+        let map = Grid::parse(contents, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let galaxies = get_positions(&map);
+        //dbg!(&galaxies);
+        let xempty = empty_row_indexes(&map);
+        let yempty = empty_row_indexes(&map.transpose());
+
+        //dbg!(&xempty);
+        //dbg!(&yempty);
+
+        return Ok(Universe{galaxies, xempty, yempty});
+    }
+
+    fn part1(universe: &Universe) -> isize {
+        total_distance(universe, 2)
+    }
+
+    fn part2(universe: &Universe) -> isize {
+        total_distance(universe, 1000000)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day11::parse("...#......\n.......#..\n#.........\n..........\n......#...\n.#........\n.........#\n..........\n.......#..\n#...#.....").unwrap();
+        assert_eq!(Day11::part1(&input), 374);
+        assert_eq!(total_distance(&input, 10), 1030);
+        assert_eq!(total_distance(&input, 100), 8410);
+    }
+
+    fuzz_parse!(Day11);
+}
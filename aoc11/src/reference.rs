@@ -0,0 +1,42 @@
+use aoc_common::reference::Reference;
+use crate::{Day11, Universe};
+
+fn naive_distance(universe: &Universe, grow: isize) -> isize {
+    // walk each pair's rows and columns, those without any galaxy count for `grow`
+    let mut res = 0;
+    for (i, a) in universe.galaxies.iter().enumerate() {
+        for b in &universe.galaxies[i+1..] {
+            for x in a.0.min(b.0)..a.0.max(b.0) {
+                res += if universe.galaxies.iter().any(|g| g.0 == x) { 1 } else { grow };
+            }
+            for y in a.1.min(b.1)..a.1.max(b.1) {
+                res += if universe.galaxies.iter().any(|g| g.1 == y) { 1 } else { grow };
+            }
+        }
+    }
+    return res;
+}
+
+impl Reference for Day11 {
+    fn reference_part1(universe: &Universe) -> isize {
+        naive_distance(universe, 2)
+    }
+
+    fn reference_part2(universe: &Universe) -> isize {
+        naive_distance(universe, 1000000)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::reference::first_divergence;
+
+    #[test]
+    fn test_matches_reference() {
+        if let Some(divergence) = first_divergence::<Day11>(0..30, 15) {
+            panic!("{}", divergence);
+        }
+    }
+}
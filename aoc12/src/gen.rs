@@ -0,0 +1,46 @@
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day12;
+
+impl Generator for Day12 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Each row is drawn from a real arrangement, so that there is at least one, then partly hidden.
+        Rows stay short: unfolded five times, the count of arrangements must fit in an i64.
+        */
+        let mut res = String::new();
+        for _ in 0..size.max(1) {
+            let len = rng.range(4, 14) as usize;
+            let mut springs: Vec<char> = (0..len).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect();
+            if !springs.contains(&'#') {
+                springs[rng.below(len)] = '#';
+            }
+
+            let groups: Vec<String> = springs.split(|c| *c == '.')
+                .filter(|g| g.len() > 0)
+                .map(|g| g.len().to_string())
+                .collect();
+
+            let hidden: String = springs.iter().map(|c| if rng.chance(0.5) { '?' } else { *c }).collect();
+            res += &format!("{} {}\n", hidden, groups.join(","));
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day12::parse(&Day12::generate(&mut Rng::new(seed), 20)).unwrap();
+            // every row has an arrangement at least
+            assert!(Day12::part1(&input) >= 20);
+            assert!(Day12::part2(&input) >= 20);
+        }
+    }
+}
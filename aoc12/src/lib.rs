@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum SpringType {
+    Unknown = 0,
+    Operational = 1,
+    Broken = 2,
+}
+
+fn parse_springs(s: &str) -> Vec<SpringType> {
+    s.chars().map(|c| match c {
+        '?' => SpringType::Unknown,
+        '#' => SpringType::Broken,
+        _ => SpringType::Operational,
+    }).collect()
+}
+
+fn repeated_join(s: &str, count:&usize, joiner: &str) -> String {
+    let mut res = (*s).to_owned();
+    for _ in 1..*count {
+        res += joiner;
+        res += s;
+    }
+    return res;
+}
+
+fn parse_springs_map(line: &Line, repeat: &Option<usize>) -> Result<(Vec<SpringType>, Vec<i32>), ParseError> {
+    let repeat_count = repeat.unwrap_or(1);
+
+    let (springs_str, info_str) = line.text.split_once(' ').ok_or_else(|| line.error_line("expected `<springs> <group sizes>`"))?;
+    if let Some((pos, c)) = springs_str.char_indices().find(|(_, c)| !"?#.".contains(*c)) {
+        return Err(line.error(&springs_str[pos..(pos+c.len_utf8())], "expected a spring among `?`, `#` and `.`"));
+    }
+    let springs = parse_springs( &repeated_join(springs_str, &repeat_count, "?") );
+
+    let mut group_sizes: Vec<i32> = vec![];
+    for num in info_str.split(',') {
+        group_sizes.push(line.number::<u16>(num)? as i32);
+    }
+    let info = group_sizes.repeat(repeat_count);
+
+    return Ok((springs, info));
+}
+
+fn can_place_springset(springs: &Vec<SpringType>, length: &usize) -> bool {
+    springs.len() >= *length && springs[..*length].iter().all(|&x| x != SpringType::Operational)
+}
+
+type SpringCache = HashMap<(Vec<SpringType>, Vec<i32>), i64>;
+
+fn count_spring_possibilities(springs: Vec<SpringType>, info: Vec<i32>, cache: &mut SpringCache) -> i64 {
+    // memoized front for count_spring_possibilities_uncached
+    let key = (springs, info);
+    if let Some(res) = cache.get(&key) {
+        return *res;
+    }
+    let res = count_spring_possibilities_uncached(&key.0, &key.1, cache);
+    cache.insert(key, res);
+    return res;
+}
+
+fn count_spring_possibilities_uncached(springs: &Vec<SpringType>, info: &Vec<i32>, cache: &mut SpringCache) -> i64 {
+    /*
+    Each springset has a set of possible index it could start at (not necessarily contiguous).
+    For ease of implementation, use recursion.
+    */
+    // base case: string ended
+    if springs.len() == 0 {
+        if info.len() == 0 {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+    // read first char
+    let first = *springs.get(0).unwrap();
+    if first == SpringType::Broken {
+        if info.len() == 0 {
+            return 0;
+        }
+        let mut res = 0;
+        // try place springset now
+        let first_set_length = *info.get(0).unwrap() as usize;
+        if can_place_springset(springs, &first_set_length) {
+            if springs.len() == first_set_length {
+                // place until end
+                if info.len() == 1 {
+                    res += 1;
+                }
+            } else {
+                // leave one operational between sets
+                if *springs.get(first_set_length).unwrap() != SpringType::Broken {
+                    res += count_spring_possibilities(springs[(first_set_length+1)..].to_vec(), info[1..].to_vec(), cache);
+                }
+            }
+        }
+        return res;
+    } else if first == SpringType::Unknown {
+        let mut res = 0;
+        // try place springset later
+        res += count_spring_possibilities(springs[1..].to_vec(), info.clone(), cache);
+        // try place springset now
+        if info.len() > 0 {
+            let first_set_length = *info.get(0).unwrap() as usize;
+            if can_place_springset(springs, &first_set_length) {
+                if springs.len() == first_set_length {
+                    // place until end
+                    if info.len() == 1 {
+                        res += 1;
+                    }
+                } else {
+                    // leave one operational between sets
+                    if *springs.get(first_set_length).unwrap() != SpringType::Broken {
+                        res += count_spring_possibilities(springs[(first_set_length+1)..].to_vec(), info[1..].to_vec(), cache);
+                    }
+                }
+            }
+        }
+        return res;
+    } else {
+        // place springs later
+        return count_spring_possibilities(springs[1..].to_vec(), info.clone(), cache);
+    }
+}
+
+fn count_all_possibilities(rows: &Vec<(Vec<SpringType>, Vec<i32>)>) -> i64 {
+    let mut cache: SpringCache = HashMap::new();
+    let mut res = 0;
+    for (springs, info) in rows {
+        res += count_spring_possibilities(springs.clone(), info.clone(), &mut cache);
+    }
+    return res;
+}
+
+pub struct SpringRows {
+    folded: Vec<(Vec<SpringType>, Vec<i32>)>,
+    unfolded: Vec<(Vec<SpringType>, Vec<i32>)>,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = SpringRows;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(contents: &str) -> Result<SpringRows, ParseError> {
+        let mut rows = SpringRows{folded: vec![], unfolded: vec![]};
+        for line in lines(contents).filter(|s| s.len()>0) {
+            rows.folded.push(parse_springs_map(&line, &None)?);
+            rows.unfolded.push(parse_springs_map(&line, &Some(5))?);
+        }
+        return Ok(rows);
+    }
+
+    fn part1(rows: &SpringRows) -> i64 {
+        count_all_possibilities(&rows.folded)
+    }
+
+    fn part2(rows: &SpringRows) -> i64 {
+        count_all_possibilities(&rows.unfolded)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day12::parse("???.### 1,1,3\n.??..??...?##. 1,1,3\n?#?#?#?#?#?#?#? 1,3,1,6\n????.#...#... 4,1,1\n????.######..#####. 1,6,5\n?###???????? 3,2,1\n").unwrap();
+        assert_eq!(Day12::part1(&input), 21);
+        assert_eq!(Day12::part2(&input), 525152);
+    }
+
+    fuzz_parse!(Day12);
+}
@@ -0,0 +1,127 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day13;
+
+// a mirror between two rows (horizontal) or two columns, before index `line`
+#[derive(Copy, Clone, PartialEq)]
+struct Mirror {
+    horizontal: bool,
+    line: usize,
+}
+
+fn partner(height: usize, width: usize, mirror: Mirror, row: usize, col: usize) -> Option<(usize, usize)> {
+    // the cell reflected by the mirror, None when the reflection falls out of the block
+    let (pos, len) = if mirror.horizontal { (row, height) } else { (col, width) };
+    let reach = mirror.line.min(len - mirror.line);
+    if pos + reach < mirror.line || pos >= mirror.line + reach {
+        return None;
+    }
+    let reflected = 2*mirror.line - 1 - pos;
+    return Some(if mirror.horizontal { (reflected, col) } else { (row, reflected) });
+}
+
+fn smudge_count(block: &Grid<bool>, mirror: Mirror) -> usize {
+    // count of cells differing from their reflection, each pair counted once
+    let differing = block.iter()
+        .filter(|((row, col), v)| partner(block.height(), block.width(), mirror, *row, *col).is_some_and(|p| block[p] != **v))
+        .count();
+    return differing / 2;
+}
+
+fn all_mirrors(height: usize, width: usize) -> Vec<Mirror> {
+    (1..height).map(|line| Mirror{horizontal: true, line})
+        .chain((1..width).map(|line| Mirror{horizontal: false, line}))
+        .collect()
+}
+
+fn find(parent: &mut Vec<usize>, x: usize) -> usize {
+    if parent[x] != x {
+        parent[x] = find(parent, parent[x]);
+    }
+    return parent[x];
+}
+
+fn random_block(rng: &mut Rng) -> Grid<bool> {
+    /*
+    A block with exactly one perfect mirror A and exactly one mirror B off by a single smudge.
+    Cells forced equal by both mirrors are grouped with a union-find and get the same random value,
+    then one cell reflected by B but not by A is flipped. Blocks with another candidate line are retried.
+    */
+    loop {
+        let (height, width) = (rng.range(5, 15) as usize, rng.range(5, 15) as usize);
+        let mirrors = all_mirrors(height, width);
+        let a = *rng.choose(&mirrors);
+        let b = *rng.choose(&mirrors);
+        if a == b {
+            continue;
+        }
+
+        let mut parent: Vec<usize> = (0..height*width).collect();
+        for row in 0..height {
+            for col in 0..width {
+                for mirror in [a, b] {
+                    if let Some((r, c)) = partner(height, width, mirror, row, col) {
+                        let (x, y) = (find(&mut parent, row*width + col), find(&mut parent, r*width + c));
+                        parent[x] = y;
+                    }
+                }
+            }
+        }
+        let values: Vec<bool> = (0..height*width).map(|_| rng.chance(0.5)).collect();
+        let mut block = Grid::from_fn(height, width, |row, col| values[find(&mut parent, row*width + col)]);
+
+        let smudges: Vec<(usize, usize)> = block.iter()
+            .map(|(pos, _)| pos)
+            .filter(|(row, col)| partner(height, width, b, *row, *col).is_some() && partner(height, width, a, *row, *col).is_none())
+            .collect();
+        if smudges.is_empty() {
+            continue;
+        }
+        let smudge = *rng.choose(&smudges);
+        block[smudge] = !block[smudge];
+
+        let counts: Vec<(Mirror, usize)> = mirrors.iter().map(|m| (*m, smudge_count(&block, *m))).collect();
+        let perfect: Vec<Mirror> = counts.iter().filter(|(_, n)| *n == 0).map(|(m, _)| *m).collect();
+        let smudged: Vec<Mirror> = counts.iter().filter(|(_, n)| *n == 1).map(|(m, _)| *m).collect();
+        if perfect == vec![a] && smudged == vec![b] {
+            return block;
+        }
+    }
+}
+
+impl Generator for Day13 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let blocks: Vec<String> = (0..size.max(1))
+            .map(|_| random_block(rng).display(|b| if *b { '#' } else { '.' }).to_string())
+            .collect();
+        return blocks.join("\n");
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_partner() {
+        // mirror between rows 1 and 2 of 5 rows: rows 0 to 3 are reflected
+        let mirror = Mirror{horizontal: true, line: 2};
+        assert_eq!(partner(5, 3, mirror, 0, 1), Some((3, 1)));
+        assert_eq!(partner(5, 3, mirror, 2, 0), Some((1, 0)));
+        assert_eq!(partner(5, 3, mirror, 4, 0), None);
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day13::parse(&Day13::generate(&mut Rng::new(seed), 5)).unwrap();
+            assert_eq!(input.len(), 5);
+            // one line per block, each worth at least 1
+            assert!(Day13::part1(&input) >= 5);
+            assert!(Day13::part2(&input) >= 5);
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::grid::Grid;
+use aoc_common::parse::blocks;
+
+mod gen;
+
+
+fn reflection_indexes(v: &Vec<i64>) -> Vec<usize> {
+    if v.len() < 2 {
+        return vec![];
+    }
+
+    return (1..v.len())
+        .filter(|&i| v[..i].iter()
+            .rev()
+            .zip(v[i..].iter())
+            .all(|(a,b)| a==b))
+        .collect()
+}
+
+fn count_high_bits(x: &i64) -> i64{
+    let mut res = 0;
+    let mut val = *x;
+    while val > 0 {
+        res += val%2;
+        val /= 2;
+    }
+    return res;
+}
+
+fn reflection_indexes_smudged(v: &Vec<i64>) -> Vec<usize> {
+    if v.len() < 2 {
+        return vec![];
+    }
+
+    let refl = reflection_indexes(v);
+
+    return (1..v.len())
+        .filter(|i| !refl.contains(i))
+        .filter(|&i| v[..i].iter()
+            .rev()
+            .zip(v[i..].iter())
+            .map(|(a,b)| count_high_bits(&(a^b)))
+            .sum::<i64>() == 1)
+        .collect()
+}
+
+fn line_to_int(v: &[bool]) -> i64 {
+    let mut res = 0;
+    for e in v {
+        if *e {
+            res = (res*2) +1;
+        } else {
+            res *= 2;
+        }
+    }
+    return res;
+}
+
+fn block_score(block: &Grid<bool>) -> usize {
+    let mut res = 0;
+
+    res += reflection_indexes(&block.transpose().rows().map(line_to_int).collect()).iter().sum::<usize>();
+    res += 100*( reflection_indexes(&block.rows().map(line_to_int).collect()).iter().sum::<usize>() );
+
+    return res;
+}
+
+fn block_score_smudged(block: &Grid<bool>) -> usize {
+    let mut res = 0;
+
+    res += reflection_indexes_smudged(&block.transpose().rows().map(line_to_int).collect()).iter().sum::<usize>();
+    res += 100*( reflection_indexes_smudged(&block.rows().map(line_to_int).collect()).iter().sum::<usize>() );
+
+    return res;
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Grid<bool>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<Vec<Grid<bool>>, ParseError> {
+        let mut res: Vec<Grid<bool>> = vec![];
+        for lines in blocks(contents) {
+            let block = Grid::from_lines(&lines, |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })?;
+
+            //println!("{}", block.display(|c| if *c { '#' } else { ' ' }));
+
+            res.push(block);
+        }
+
+        return Ok(res);
+    }
+
+    fn part1(blocks: &Vec<Grid<bool>>) -> usize {
+        blocks.iter().map(block_score).sum()
+    }
+
+    fn part2(blocks: &Vec<Grid<bool>>) -> usize {
+        blocks.iter().map(block_score_smudged).sum()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day13::parse("#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+
+").unwrap();
+        assert_eq!(Day13::part1(&input), 405);
+        assert_eq!(Day13::part2(&input), 400);
+    }
+
+    #[test]
+    fn test_reflection_indexes_empty() {
+        assert_eq!(reflection_indexes(&vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_sym() {
+        assert_eq!(reflection_indexes(&vec![2, 2]), vec![1]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_offleft() {
+        assert_eq!(reflection_indexes(&vec![4, 5, 2, 2]), vec![3]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_offright() {
+        assert_eq!(reflection_indexes(&vec![1, 2, 2, 1, 3, 5]), vec![2]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_smudged_empty() {
+        assert_eq!(reflection_indexes_smudged(&vec![]), vec![]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_smudged_sym() {
+        assert_eq!(reflection_indexes_smudged(&vec![2, 3]), vec![1]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_smudged_offleft() {
+        assert_eq!(reflection_indexes_smudged(&vec![2, 5, 2, 6]), vec![3]);
+    }
+
+    #[test]
+    fn test_reflection_indexes_smudged_offright() {
+        assert_eq!(reflection_indexes_smudged(&vec![1, 7, 3, 1, 3, 5]), vec![2]);
+    }
+
+    fuzz_parse!(Day13);
+}
@@ -0,0 +1,80 @@
+use aoc_common::animate::Animate;
+use aoc_common::cycle::find_cycle;
+use aoc_common::geometry::Direction;
+use aoc_common::grid::Grid;
+use aoc_common::render::{Color, Picture};
+use crate::{Day14, Platform, platform_weight, roll_platform_round, roll_platform_up, roll_platform_west};
+
+const BLOCK: Color = Color::new(110, 110, 120);
+const ROCK: Color = Color::new(230, 150, 50);
+
+fn tilt(blocks: &Grid<bool>, rolling: &Grid<bool>, towards: Direction) -> Grid<bool> {
+    match towards {
+        Direction::Top => roll_platform_up(blocks, rolling),
+        Direction::Left => roll_platform_west(blocks, rolling),
+        Direction::Bottom => roll_platform_up(&blocks.flip_vertical(), &rolling.flip_vertical()).flip_vertical(),
+        Direction::Right => roll_platform_west(&blocks.flip_horizontal(), &rolling.flip_horizontal()).flip_horizontal(),
+    }
+}
+
+fn compass(dir: Direction) -> &'static str {
+    match dir {
+        Direction::Top => "north",
+        Direction::Left => "west",
+        Direction::Bottom => "south",
+        Direction::Right => "east",
+    }
+}
+
+fn picture(platform: &Platform, rolling: &Grid<bool>) -> Picture {
+    let mut picture = Picture::from_grid(&platform.blocks, Color::gray(25), |b| if *b { BLOCK } else { Color::gray(25) });
+    for (pos, r) in rolling.iter() {
+        if *r {
+            picture.cells[pos] = ROCK;
+        }
+    }
+    return picture;
+}
+
+impl Animate for Day14 {
+    fn animate(platform: &Platform, show: &mut dyn FnMut(&Picture, &str)) {
+        // every tilt of the spin cycles, up to the first repeated state
+        let blocks = &platform.blocks;
+        let cycle = find_cycle(&platform.rolling, |rolling: &Grid<bool>| roll_platform_round(blocks, rolling));
+
+        let mut rolling = platform.rolling.clone();
+        show(&picture(platform, &rolling), &format!("load on the north beams {}", platform_weight(&rolling)));
+        for round in 1..=(cycle.prefix + cycle.period) {
+            for towards in [Direction::Top, Direction::Left, Direction::Bottom, Direction::Right] {
+                rolling = tilt(blocks, &rolling, towards);
+                show(&picture(platform, &rolling), &format!("cycle {}, tilted {}, load on the north beams {}", round, compass(towards), platform_weight(&rolling)));
+            }
+        }
+        show(&picture(platform, &rolling), &format!("same as after cycle {}, repeating every {} cycles", cycle.prefix, cycle.period));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_animate() {
+        let input = Day14::parse("O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....").unwrap();
+        let mut captions: Vec<String> = vec![];
+        let mut last: Option<Picture> = None;
+        Day14::animate(&input, &mut |picture, caption| {
+            captions.push(caption.to_string());
+            last = Some(picture.clone());
+        });
+
+        // the example repeats every 7 cycles from the third one
+        assert_eq!(captions.len(), 1 + 4*10 + 1);
+        assert_eq!(captions[1], "cycle 1, tilted north, load on the north beams 136");
+        assert_eq!(captions[41], "same as after cycle 3, repeating every 7 cycles");
+        let rocks = last.unwrap().cells.iter().filter(|(_, c)| **c == ROCK).count();
+        assert_eq!(rocks, input.rolling.iter().filter(|(_, r)| **r).count());
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day14;
+
+impl Generator for Day14 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(2);
+        let map = Grid::from_fn(side, side, |_, _| match rng.below(20) {
+            0..=3 => 'O',
+            4..=6 => '#',
+            _ => '.',
+        });
+        return map.display(|c| *c).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let contents = Day14::generate(&mut Rng::new(seed), 12);
+            let input = Day14::parse(&contents).unwrap();
+            // a rock weighs at most the height of the platform
+            let rocks = contents.chars().filter(|c| *c == 'O').count();
+            assert!(Day14::part1(&input) <= rocks*12);
+            assert!(Day14::part2(&input) <= rocks*12);
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::cycle::find_cycle;
+use aoc_common::grid::Grid;
+
+mod animate;
+mod gen;
+
+fn roll_line_left(blocks: &[bool], rolling: &[bool]) -> Vec<bool> {
+    let mut min_pos: usize = 0;
+    let mut res: Vec<bool> = blocks.iter().map(|_| false).clone().collect();
+
+    for (i, (b,r)) in blocks.iter().zip(rolling).enumerate() {
+        if *b {
+            min_pos = i+1;
+        } else if *r {
+            *res.get_mut(min_pos).unwrap() = true;
+            min_pos += 1;
+        }
+    }
+
+    return res;
+}
+
+fn roll_platform_west(blocks: &Grid<bool>, rolling: &Grid<bool>) -> Grid<bool> {
+    Grid::from_rows(
+        blocks.rows()
+            .zip(rolling.rows())
+            .map(|(b,r)| roll_line_left(b,r))
+            .collect()
+    )
+}
+
+fn roll_platform_up(blocks: &Grid<bool>, rolling: &Grid<bool>) -> Grid<bool> {
+    roll_platform_west(
+        &blocks.transpose(),
+        &rolling.transpose()
+    ).transpose()
+}
+
+fn roll_platform_round(blocks: &Grid<bool>, rolling: &Grid<bool>) -> Grid<bool> {
+    // roll towards N,W,S,E
+    // [N,W,S,E] -> [W,S,E,N]
+    let mut r = rolling.rotate_ccw();
+    let mut b = blocks.rotate_ccw();
+
+    for _ in 0..4 {
+        // [N,W,S,E] -> [E,S,W,N]
+        r = roll_platform_west(&b, &r).rotate_cw();
+        b = b.rotate_cw();
+    }
+
+    let res = r.rotate_cw();
+    return res;
+}
+
+fn platform_weight(rolling: &Grid<bool>) -> usize {
+    let height = rolling.height();
+    rolling.columns().map(
+        |col| col.enumerate()
+                 .filter(|(_,&x)| x)
+                 .map(|(i,_)| height-i)
+                 .sum::<usize>()
+        ).sum()
+}
+
+pub struct Platform {
+    blocks: Grid<bool>,
+    rolling: Grid<bool>,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Platform;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<Platform, ParseError> {
+        let map = Grid::parse(contents, |c| if ".#O".contains(c) { Some(c) } else { None })?;
+        let blocks = map.map(|c| *c == '#');
+        let rolling = map.map(|c| *c == 'O');
+
+        return Ok(Platform{blocks, rolling});
+    }
+
+    fn part1(platform: &Platform) -> usize {
+        let rolledup = roll_platform_up(&platform.blocks, &platform.rolling);
+
+        return platform_weight(&rolledup);
+    }
+
+    fn part2(platform: &Platform) -> usize {
+        let blocks = &platform.blocks;
+
+        let round = |rolling: &Grid<bool>| roll_platform_round(blocks, rolling);
+        let cycle = find_cycle(&platform.rolling, round);
+        // skip the loops up to the cycle before the end
+        let current = cycle.nth_state(&platform.rolling, round, 1000000000);
+
+        // 1345 too low
+        return platform_weight(&current);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day14::parse("O....#....\nO.OO#....#\n.....##...\nOO.#O....O\n.O.....O#.\nO.#..O.#.#\n..O..#O..O\n.......O..\n#....###..\n#OO..#....").unwrap();
+        assert_eq!(Day14::part1(&input), 136);
+        assert_eq!(Day14::part2(&input), 64);
+    }
+
+    #[test]
+    fn test_roll_line_left() {
+        // .O.#..O -> O..#O..
+        let blocks = vec![false, false, false, true, false, false, false];
+        let rolling = vec![false, true, false, false, false, false, true];
+        assert_eq!(roll_line_left(&blocks, &rolling), vec![true, false, false, false, true, false, false]);
+    }
+
+    #[test]
+    fn test_roll_line_left_stacked() {
+        // #.OO.O -> #OOO..
+        let blocks = vec![true, false, false, false, false, false];
+        let rolling = vec![false, false, true, true, false, true];
+        assert_eq!(roll_line_left(&blocks, &rolling), vec![false, true, true, true, false, false]);
+    }
+
+    fuzz_parse!(Day14);
+}
@@ -0,0 +1,42 @@
+use aoc_common::Generator;
+use aoc_common::gen::random_word;
+use aoc_common::rng::Rng;
+use crate::Day15;
+
+impl Generator for Day15 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // labels come from a small pool so that lenses get replaced and removed
+        let count = size.max(1);
+        let labels: Vec<String> = (0..(count/4).max(1)).map(|_| {
+            let len = rng.range(1, 6) as usize;
+            random_word(rng, len, "abcdefghijklmnopqrstuvwxyz")
+        }).collect();
+
+        let steps: Vec<String> = (0..count).map(|_| {
+            let label = rng.choose(&labels).clone();
+            if rng.chance(0.3) {
+                label + "-"
+            } else {
+                format!("{}={}", label, rng.range(1, 9))
+            }
+        }).collect();
+        return steps.join(",") + "\n";
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day15::parse(&Day15::generate(&mut Rng::new(seed), 40)).unwrap();
+            assert_eq!(input.len(), 40);
+            Day15::part1(&input);
+            Day15::part2(&input);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::lines;
+
+mod gen;
+
+fn hash(s: &str) -> u64 {
+    let mut res = 0;
+    for c in s.chars() {
+        res += (c as u8) as u64;
+        res *= 17;
+        res %= 256;
+    }
+    return res;
+}
+
+fn split_symbol(s: &str) -> (String, char, u8) {
+    match s.chars().last().unwrap() {
+        '-' => (s[..(s.len()-1)].to_string(), '-', 0),
+        c => (s[..(s.len()-2)].to_string(), '=', (c as u8)-b'0'),
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<Vec<String>, ParseError> {
+        let line = lines(contents).next().unwrap();
+        let steps: Vec<&str> = line.text.split(',').collect();
+        for step in &steps {
+            // either "<label>-" or "<label>=<focal length>"
+            let label = match step.find(['-', '=']) {
+                Some(pos) if &step[pos..] == "-" => &step[..pos],
+                Some(pos) if step[pos..].len() == 2 && step[(pos+1)..].chars().all(|c| c.is_ascii_digit()) => &step[..pos],
+                _ => return Err(line.error(step, "expected `<label>-` or `<label>=<focal length>`")),
+            };
+            if label.len() == 0 || !label.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(line.error(step, "expected a label made of lowercase letters"));
+            }
+        }
+
+        return Ok(steps.iter().map(|s| s.to_string()).collect());
+    }
+
+    fn part1(steps: &Vec<String>) -> u64 {
+        // first hash check
+        let mut res = 0;
+        for sym in steps {
+            res += hash(sym);
+        }
+        return res;
+    }
+
+    fn part2(steps: &Vec<String>) -> usize {
+        // apply box operations
+        let mut boxes: [Vec<(String,u8)>;256] = vec![Vec::new();256].try_into().expect("static");
+        for sym in steps {
+            let (label, operation, focal) = split_symbol(sym);
+            let target_box = hash(&label);
+            let box_vec = boxes.get_mut(target_box as usize).unwrap();
+            let index = box_vec.iter().position(|x| x.0==label);
+            if operation == '-' {
+                if index.is_some() {
+                    box_vec.remove(index.unwrap());
+                }
+            } else {
+                if index.is_some() {
+                    *box_vec.get_mut(index.unwrap()).unwrap() = (label, focal);
+                } else {
+                    box_vec.push((label, focal));
+                }
+            }
+        }
+
+        // compute lens hash
+        let mut power = 0;
+        for (i,boxvec) in boxes.iter().enumerate() {
+            for (j, (_,lens)) in boxvec.iter().enumerate() {
+                power += (i+1) * (j+1) * (*lens as usize);
+            }
+        }
+        return power;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+    use aoc_common::parse::normalize;
+
+    #[test]
+    fn test_example() {
+        let input = Day15::parse("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n").unwrap();
+        assert_eq!(Day15::part1(&input), 1320);
+        assert_eq!(Day15::part2(&input), 145);
+    }
+
+    #[test]
+    fn test_example_crlf() {
+        let input = Day15::parse(&normalize("rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\r\n")).unwrap();
+        assert_eq!(Day15::part2(&input), 145);
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+    }
+
+    fuzz_parse!(Day15);
+}
@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+use aoc_common::animate::Animate;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+use aoc_common::render::{Color, Picture};
+use crate::{Day16, Reflector, propagate};
+
+const MIRROR: Color = Color::new(70, 130, 220);
+const ENERGIZED: Color = Color::new(150, 110, 40);
+const HEAD: Color = Color::new(255, 230, 120);
+
+impl Animate for Day16 {
+    fn animate(map: &Grid<Reflector>, show: &mut dyn FnMut(&Picture, &str)) {
+        // the beam of part 1 spreading, its heads in bright
+        let empty = Picture::from_grid(map, Color::gray(25), |cell| match cell {
+            Reflector::None => Color::gray(25),
+            _ => MIRROR,
+        });
+        let mut energized: HashSet<Point> = HashSet::new();
+        propagate(map, (Point::new(0, 0), Direction::Right), |explored, heads| {
+            let mut picture = empty.clone();
+            energized.extend(explored.iter().map(|(pos, _)| *pos));
+            for pos in energized.iter() {
+                picture.cells[*pos] = picture.cells[*pos].mix(ENERGIZED, 0.8);
+            }
+            for (pos, _) in heads {
+                picture.cells[*pos] = HEAD;
+            }
+            show(&picture, &format!("{} beam heads, {} tiles energized", heads.len(), energized.len()));
+        });
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_animate() {
+        let input = Day16::parse(".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....").unwrap();
+        let mut captions: Vec<String> = vec![];
+        Day16::animate(&input, &mut |_, caption| captions.push(caption.to_string()));
+        assert_eq!(captions[0], "1 beam heads, 1 tiles energized");
+        assert!(captions.last().unwrap().ends_with(&format!(" {} tiles energized", Day16::part1(&input))));
+    }
+}
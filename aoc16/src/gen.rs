@@ -0,0 +1,29 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day16;
+
+impl Generator for Day16 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        let mirrors: Vec<char> = "|-/\\".chars().collect();
+        let map = Grid::from_fn(side, side, |_, _| if rng.chance(0.15) { *rng.choose(&mirrors) } else { '.' });
+        return map.display(|c| *c).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day16::parse(&Day16::generate(&mut Rng::new(seed), 15)).unwrap();
+            let energized = Day16::part1(&input);
+            assert!(energized >= 1 && energized <= Day16::part2(&input));
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::cmp::max;
+use aoc_common::{ParseError, Solution};
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::grid::Grid;
+
+mod animate;
+mod gen;
+mod render;
+
+
+#[derive(Copy,Clone)]
+pub enum Reflector {
+    None=0,             // .
+    SplitHorizontal=1,  // -
+    SplitVertical=2,    // |
+    ReflectTopLeft=3,   // /
+    ReflectTopRight=4,  // \
+}
+
+fn parse_symbol(c: &char) -> Reflector {
+    match c {
+        '-'  => Reflector::SplitHorizontal,
+        '|'  => Reflector::SplitVertical,
+        '/'  => Reflector::ReflectTopLeft,
+        '\\' => Reflector::ReflectTopRight,
+        _    => Reflector::None,
+    }
+}
+
+fn exit_directions(input_dir: &Direction, cell: &Reflector) -> Vec<Direction> {
+    /*
+    Get the directios when coming into a cell.
+    Input direction is reversed (i.e. Right = coming from Left)
+    */
+    match cell {
+        Reflector::None => vec![*input_dir],
+        Reflector::SplitHorizontal => {
+            match input_dir {
+                Direction::Left | Direction::Right => vec![*input_dir],
+                _ => vec![Direction::Left, Direction::Right],
+            }
+        },
+        Reflector::SplitVertical => {
+            match input_dir {
+                Direction::Top | Direction::Bottom => vec![*input_dir],
+                _ => vec![Direction::Top, Direction::Bottom],
+            }
+        },
+        Reflector::ReflectTopLeft => {
+            match input_dir {
+                Direction::Right => vec![Direction::Top],
+                Direction::Left => vec![Direction::Bottom],
+                Direction::Bottom => vec![Direction::Left],
+                Direction::Top => vec![Direction::Right],
+            }
+        },
+        Reflector::ReflectTopRight => {
+            match input_dir {
+                Direction::Right => vec![Direction::Bottom],
+                Direction::Left => vec![Direction::Top],
+                Direction::Bottom => vec![Direction::Right],
+                Direction::Top => vec![Direction::Left],
+            }
+        }
+    }
+}
+
+fn propagate(map:&Grid<Reflector>, initial: (Point,Direction), mut on_wave: impl FnMut(&HashSet<(Point,Direction)>, &Vec<(Point,Direction)>)) -> HashSet<(Point,Direction)> {
+    /*
+    Moves all the beam heads one tile at a time, shows every wave to on_wave.
+    Returns the (tile, direction) pairs the beam went through.
+    */
+    let bounds = map.bounds();
+    let mut explored: HashSet<(Point,Direction)> = HashSet::new();
+    explored.insert( initial );
+    let mut heads: Vec<(Point,Direction)> = vec![initial];
+
+    while heads.len() > 0 {
+        on_wave(&explored, &heads);
+        let mut next_heads: Vec<(Point,Direction)> = vec![];
+        for (pos, dir) in heads {
+            let sym_entered = &map[pos];
+            for exit_dir in exit_directions(&dir, sym_entered) {
+                //println!("At {:?}, entered {} from dir {:?}, exited {:?}", pos, *sym_entered as i32, dir, exit_dir);
+                let next = pos.checked_step(exit_dir, &bounds);
+                if next.is_some() {
+                    //println!(" > Move from {:?} to {:?} on dir {:?}", pos, next.unwrap(), exit_dir);
+                    let next_head = (next.unwrap(),exit_dir);
+                    if !explored.contains(&next_head) {
+                        next_heads.push(next_head);
+                        explored.insert(next_head);
+                    } /* else {
+                        println!(" x drop");
+                    }*/
+                }
+            }
+        }
+        heads = next_heads;
+    }
+
+    return explored;
+}
+
+fn energized_from(map:&Grid<Reflector>, input_dir:Direction, position: usize) -> Grid<bool> {
+    let width = map.width();
+
+    let initial: (Point,Direction);
+    if input_dir == Direction::Right {
+        initial = (Point::new(position as i64, 0), Direction::Right);
+    } else if input_dir == Direction::Left {
+        initial = (Point::new(position as i64, width as i64 - 1), Direction::Left);
+    } else {
+        initial = (Point::new(0, position as i64), Direction::Bottom);
+    }
+
+    let explored = propagate(map, initial, |_, _| {});
+
+    let mut explored_map = Grid::new(map.height(), map.width(), false);
+    for (pos,_) in explored {
+        explored_map[pos] = true;
+    }
+
+    //print!("{}", explored_map.display(|v| if *v { '#' } else { ' ' }));
+
+    return explored_map;
+}
+
+fn count_energized_from(map:&Grid<Reflector>, input_dir:Direction, position: usize) -> usize {
+    energized_from(map, input_dir, position).iter().filter(|(_,v)| **v).count()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Grid<Reflector>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<Grid<Reflector>, ParseError> {
+        let map = Grid::parse(contents, |c| if ".-|/\\".contains(c) { Some(parse_symbol(&c)) } else { None })?;
+
+        return Ok(map);
+    }
+
+    fn part1(map: &Grid<Reflector>) -> usize {
+        count_energized_from(map, Direction::Right, 0)
+    }
+
+    fn part2(map: &Grid<Reflector>) -> usize {
+        let mut res2 = 0;
+        for x in 0..(map.height()) {
+            res2 = max( res2, count_energized_from(map, Direction::Left, x) );
+            res2 = max( res2, count_energized_from(map, Direction::Right, x) );
+        }
+        for y in 0..(map.width()) {
+            res2 = max( res2, count_energized_from(map, Direction::Bottom, y) );
+        }
+        return res2;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day16::parse(".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....").unwrap();
+        assert_eq!(Day16::part1(&input), 46);
+        assert_eq!(Day16::part2(&input), 51);
+    }
+
+    fuzz_parse!(Day16);
+}
@@ -0,0 +1,39 @@
+use aoc_common::geometry::Direction;
+use aoc_common::grid::Grid;
+use aoc_common::render::{Color, Picture, Render};
+use crate::{Day16, Reflector, energized_from};
+
+const ENERGIZED: Color = Color::new(250, 200, 60);
+const MIRROR: Color = Color::new(70, 130, 220);
+
+impl Render for Day16 {
+    fn render(map: &Grid<Reflector>) -> Picture {
+        // the tiles energized by the beam of part 1, and the mirrors and splitters
+        let energized = energized_from(map, Direction::Right, 0);
+        let mut picture = Picture::from_grid(map, Color::gray(30), |cell| match cell {
+            Reflector::None => Color::gray(30),
+            _ => MIRROR,
+        });
+        for (pos, lit) in energized.iter() {
+            if *lit {
+                picture.cells[pos] = picture.cells[pos].mix(ENERGIZED, 0.7);
+            }
+        }
+        return picture;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_render() {
+        let input = Day16::parse(".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....").unwrap();
+        let picture = Day16::render(&input);
+        let lit = picture.cells.iter().filter(|(_, c)| **c != Color::gray(30) && **c != MIRROR).count();
+        assert_eq!(lit, Day16::part1(&input));
+    }
+}
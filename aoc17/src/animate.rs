@@ -0,0 +1,74 @@
+use aoc_common::animate::Animate;
+use aoc_common::grid::Grid;
+use aoc_common::render::{Color, Picture};
+use crate::{CrucibleParams, Day17, crucible_path};
+
+const EXPANDED: Color = Color::new(40, 60, 110);
+const FRONTIER: Color = Color::new(120, 220, 255);
+const PATH: Color = Color::new(255, 255, 255);
+
+fn heat_map(map: &Grid<i32>) -> Picture {
+    // dark reds, so that the search shows over them
+    Picture::from_grid(map, Color::BLACK, |loss| Color::new(40 + 15*(*loss as u8), 20, 15))
+}
+
+impl Animate for Day17 {
+    fn animate(map: &Grid<i32>, show: &mut dyn FnMut(&Picture, &str)) {
+        /*
+        The best-first search of both crucibles: the blocks already expanded,
+        and the frontier of the blocks reached but not expanded yet.
+        A frame every half a map of expansions.
+        */
+        let batch = (map.height()*map.width()/2).max(1);
+        for (name, params) in [("crucible", CrucibleParams{max_steps: 3, min_steps: 0}), ("ultra crucible", CrucibleParams{max_steps: 10, min_steps: 4})] {
+            let mut expanded = Grid::new(map.height(), map.width(), false);
+            let mut reached = Grid::new(map.height(), map.width(), false);
+            let mut count = 0;
+            let path = crucible_path(map, params, |current, successors| {
+                expanded[current.pos] = true;
+                for (next, _) in successors {
+                    reached[next.pos] = true;
+                }
+                count += 1;
+                if count % batch == 0 {
+                    let mut picture = heat_map(map);
+                    for (pos, is_expanded) in expanded.iter() {
+                        if *is_expanded {
+                            picture.cells[pos] = picture.cells[pos].mix(EXPANDED, 0.6);
+                        } else if reached[pos] {
+                            picture.cells[pos] = FRONTIER;
+                        }
+                    }
+                    show(&picture, &format!("{}: {} states expanded", name, count));
+                }
+            });
+
+            let mut picture = heat_map(map);
+            match path {
+                Some(path) => {
+                    picture.add_path(path.states.iter().map(|state| state.pos).collect(), PATH);
+                    show(&picture, &format!("{}: best path found after {} states, heat loss {}", name, count, path.cost));
+                },
+                None => show(&picture, &format!("{}: no path found after {} states", name, count)),
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_animate() {
+        let input = Day17::parse("2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n1224686865563\n2546548887735\n4322674655533").unwrap();
+        let mut captions: Vec<String> = vec![];
+        Day17::animate(&input, &mut |_, caption| captions.push(caption.to_string()));
+        assert!(captions.len() > 2);
+        assert_eq!(captions.iter().filter(|c| c.contains("best path")).count(), 2);
+        assert!(captions.iter().any(|c| c.starts_with("crucible: best path") && c.ends_with("heat loss 102")));
+        assert!(captions.last().unwrap().ends_with("heat loss 94"));
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day17;
+
+impl Generator for Day17 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Square maps of at least 5x5: both sides split the same way into runs of 4 to 10 blocks,
+        so the ultra crucible can always reach the corner.
+        */
+        let side = size.max(5);
+        let map = Grid::from_fn(side, side, |_, _| rng.range(1, 9));
+        return map.display(|d| char::from_digit(*d as u32, 10).unwrap()).to_string();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+    use crate::{CrucibleParams, crucible_step_count};
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day17::parse(&Day17::generate(&mut Rng::new(seed), 8)).unwrap();
+            // at least the 14 blocks to the corner, at most 9 each
+            assert!((14..=14*9).contains(&crucible_step_count(&input, CrucibleParams{max_steps: 3, min_steps:0}).unwrap()));
+            assert!(crucible_step_count(&input, CrucibleParams{max_steps: 10, min_steps:4}).unwrap() >= 14);
+        }
+    }
+}
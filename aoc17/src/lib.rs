@@ -0,0 +1,148 @@
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+use aoc_common::grid::Grid;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::search::{astar, Path};
+
+mod animate;
+mod gen;
+mod render;
+
+
+#[derive(Copy,Clone,Eq,PartialEq,Hash,Debug)]
+struct SearchState {
+    pos: Point,
+    entry_dir: Direction,
+    transition_count: usize,
+}
+
+fn exit_possibilities(entry_dir:Direction, needs_turn:bool, allow_turn:bool) -> Vec<Direction> {
+    if needs_turn {
+        match entry_dir {
+            Direction::Right => vec![Direction::Bottom, Direction::Top],
+            Direction::Left => vec![Direction::Bottom, Direction::Top],
+            Direction::Bottom => vec![Direction::Left, Direction::Right],
+            Direction::Top => vec![Direction::Left, Direction::Right],
+        }
+    } else if !allow_turn {
+        vec![entry_dir]
+    } else {
+        match entry_dir {
+            Direction::Right => vec![Direction::Right, Direction::Bottom, Direction::Top],
+            Direction::Left => vec![Direction::Left, Direction::Bottom, Direction::Top],
+            Direction::Bottom => vec![Direction::Bottom, Direction::Left, Direction::Right],
+            Direction::Top => vec![Direction::Top, Direction::Left, Direction::Right],
+        }
+    }
+}
+
+struct CrucibleParams {
+    max_steps: usize,
+    min_steps: usize,
+}
+
+fn crucible_path(map:&Grid<i32>, params:CrucibleParams, mut on_expand: impl FnMut(&SearchState, &Vec<(SearchState, usize)>)) -> Option<Path<SearchState, usize>> {
+    // on_expand sees each state taken out of the queue, with the states it leads to
+    // None when the crucible cannot stop on the bottom right block, e.g. too close for the ultra crucible
+    // heads: cell and entry direction
+    let starts = [Direction::Right, Direction::Bottom].map(|dir| SearchState{pos:Point::new(0, 0), entry_dir:dir, transition_count:0});
+
+    let bounds = map.bounds();
+    let target = bounds.max;
+
+    let successors = |current: &SearchState| {
+        // map all possibilitie from 'current'
+        let mut res: Vec<(SearchState, usize)> = vec![];
+        for exit_dir in exit_possibilities(current.entry_dir, current.transition_count+1 >= params.max_steps, current.transition_count+1 >= params.min_steps) {
+            if let Some(next) = current.pos.checked_step(exit_dir, &bounds) {
+                // we can get to 'next' from 'current'.
+                let mut new_transition_count = 0;
+                if current.entry_dir==exit_dir {
+                    new_transition_count = current.transition_count+1;
+                }
+                res.push((SearchState{
+                    pos: next,
+                    entry_dir: exit_dir,
+                    transition_count: new_transition_count,
+                }, map[next] as usize));
+            }
+        }
+        on_expand(current, &res);
+        res
+    };
+
+    // every block costs at least 1
+    let heuristic = |state: &SearchState| state.pos.manhattan(target) as usize;
+
+    // the crucible can only stop where it would be allowed to turn
+    let is_goal = |state: &SearchState| state.pos == target && state.transition_count+1 >= params.min_steps;
+
+    return astar(starts, successors, heuristic, is_goal);
+}
+
+fn crucible_step_count(map:&Grid<i32>, params:CrucibleParams) -> Result<usize, String> {
+    crucible_path(map, params, |_, _| {}).map(|path| path.cost).ok_or("the crucible cannot stop on the bottom right block".to_string())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Grid<i32>;
+    type Answer1 = MaybeAnswer<usize>;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(contents: &str) -> Result<Grid<i32>, ParseError> {
+        // heat losses are 1 to 9, the A* heuristic counts on it
+        let map = Grid::parse(contents, |c| c.to_digit(10).filter(|d| *d > 0).map(|d| d as i32))?;
+
+        //print!("{}", map.display(|v| char::from_digit(*v as u32, 10).unwrap()));
+
+        return Ok(map);
+    }
+
+    fn part1(map: &Grid<i32>) -> MaybeAnswer<usize> {
+        crucible_step_count(map, CrucibleParams{max_steps: 3, min_steps:0}).into()
+    }
+
+    fn part2(map: &Grid<i32>) -> MaybeAnswer<usize> {
+        crucible_step_count(map, CrucibleParams{max_steps: 10, min_steps:4}).into()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day17::parse("2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n1224686865563\n2546548887735\n4322674655533").unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "102");
+        assert_eq!(Day17::part2(&input).to_string(), "94");
+    }
+
+    #[test]
+    fn test_example_ultra() {
+        let input = Day17::parse("111111111111\n999999999991\n999999999991\n999999999991\n999999999991\n").unwrap();
+        assert_eq!(Day17::part2(&input).to_string(), "71");
+    }
+
+    #[test]
+    fn test_small_grids() {
+        let input = Day17::parse("1\n").unwrap();
+        assert_eq!(crucible_step_count(&input, CrucibleParams{max_steps: 3, min_steps:0}), Ok(0));
+        assert!(crucible_step_count(&input, CrucibleParams{max_steps: 10, min_steps:4}).is_err());
+        let input = Day17::parse("12\n").unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "2");
+        assert_eq!(Day17::part2(&input).to_string(), "no answer (the crucible cannot stop on the bottom right block)");
+    }
+
+    #[test]
+    fn test_parse_zero_heat_loss() {
+        let e = Day17::parse("1900\n5005\n0500\n0110\n0051\n").err().unwrap();
+        assert_eq!((e.line, e.column), (1, 3));
+    }
+
+    fuzz_parse!(Day17);
+}
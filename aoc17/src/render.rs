@@ -0,0 +1,39 @@
+use aoc_common::grid::Grid;
+use aoc_common::render::{Color, Picture, Render};
+use crate::{CrucibleParams, Day17, crucible_path};
+
+const COOL: Color = Color::new(255, 235, 200);
+const HOT: Color = Color::new(150, 20, 10);
+const CRUCIBLE: Color = Color::new(30, 90, 200);
+const ULTRA_CRUCIBLE: Color = Color::new(20, 170, 90);
+
+impl Render for Day17 {
+    fn render(map: &Grid<i32>) -> Picture {
+        // the heat loss of the blocks, darker when higher, under the best paths of both crucibles when they have one
+        let mut picture = Picture::from_grid(map, COOL, |loss| COOL.mix(HOT, (*loss - 1) as f64 / 8.0));
+        for (params, color) in [(CrucibleParams{max_steps: 3, min_steps: 0}, CRUCIBLE), (CrucibleParams{max_steps: 10, min_steps: 4}, ULTRA_CRUCIBLE)] {
+            if let Some(path) = crucible_path(map, params, |_, _| {}) {
+                picture.add_path(path.states.iter().map(|state| state.pos).collect(), color);
+            }
+        }
+        return picture;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_render() {
+        let input = Day17::parse("2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n1224686865563\n2546548887735\n4322674655533").unwrap();
+        let picture = Day17::render(&input);
+        assert_eq!(picture.cells[(0, 0)], COOL.mix(HOT, 1.0/8.0));
+        // the heat lost is the one of every block entered
+        for ((path, _), expected) in picture.paths.iter().zip([102, 94]) {
+            assert_eq!(path.iter().skip(1).map(|p| input[*p]).sum::<i32>(), expected);
+        }
+    }
+}
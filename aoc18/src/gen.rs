@@ -0,0 +1,116 @@
+use aoc_common::Generator;
+use aoc_common::gen::random_loop;
+use aoc_common::geometry::{Direction, Point};
+use aoc_common::rng::Rng;
+use crate::Day18;
+
+fn direction_to(from: Point, to: Point) -> Direction {
+    *Direction::ALL.iter().find(|dir| from.step(**dir) == to).unwrap()
+}
+
+fn direction_letter(dir: Direction) -> char {
+    match dir {
+        Direction::Right => 'R',
+        Direction::Bottom => 'D',
+        Direction::Left => 'L',
+        Direction::Top => 'U',
+    }
+}
+
+fn direction_digit(dir: Direction) -> u32 {
+    match dir {
+        Direction::Right => 0,
+        Direction::Bottom => 1,
+        Direction::Left => 2,
+        Direction::Top => 3,
+    }
+}
+
+fn stretch(rng: &mut Rng, count: usize, max_gap: i64) -> Vec<i64> {
+    // increasing positions for the lines of a grid, keeps the order so a simple loop stays simple
+    // lines 2 apart at least, or the trenches of two runs side by side would touch
+    let mut res = vec![0];
+    for _ in 0..count {
+        let gap = rng.range(2, max_gap);
+        res.push(res.last().unwrap() + gap);
+    }
+    return res;
+}
+
+fn dig_plan(rng: &mut Rng, corners: &Vec<Point>, max_gap: i64) -> Vec<(Direction, i64)> {
+    // the loop as runs of a single direction, every run turning from the previous one
+    let steps: Vec<Direction> = (0..corners.len()).map(|i| direction_to(corners[i], corners[(i+1) % corners.len()])).collect();
+    let first = (0..steps.len()).find(|i| steps[*i] != steps[(i + steps.len() - 1) % steps.len()]).unwrap();
+
+    let rows = stretch(rng, corners.iter().map(|p| p.row).max().unwrap() as usize, max_gap);
+    let cols = stretch(rng, corners.iter().map(|p| p.col).max().unwrap() as usize, max_gap);
+
+    let mut res: Vec<(Direction, i64)> = vec![];
+    for i in 0..steps.len() {
+        let (from, to) = (corners[(first + i) % corners.len()], corners[(first + i + 1) % corners.len()]);
+        let len = (rows[to.row as usize] - rows[from.row as usize]).abs() + (cols[to.col as usize] - cols[from.col as usize]).abs();
+        match res.last_mut() {
+            Some((dir, total)) if *dir == steps[(first + i) % steps.len()] => *total += len,
+            _ => res.push( (steps[(first + i) % steps.len()], len) ),
+        }
+    }
+    return res;
+}
+
+fn generate_plans(rng: &mut Rng, size: usize, color_gap: i64) -> String {
+    /*
+    Both plans follow the same random simple loop, stretched differently:
+    a few meters per corner for the first one, up to color_gap per corner for the colors.
+    The colors' loop is also turned, and either plan may run the loop backwards.
+    */
+    let coarse = (size/4).max(1);
+    let mut corners = random_loop(rng, coarse, coarse, 1);
+    if rng.chance(0.5) {
+        corners.reverse();
+    }
+    let plan = dig_plan(rng, &corners, 9);
+    let mut colors = dig_plan(rng, &corners, color_gap);
+    let turns = rng.below(4);
+    for (dir, _) in colors.iter_mut() {
+        for _ in 0..turns {
+            *dir = dir.turn_right();
+        }
+    }
+
+    let mut res = String::new();
+    for ((dir, len), (color_dir, color_len)) in plan.iter().zip(colors.iter()) {
+        res += &format!("{} {} (#{:05x}{})\n", direction_letter(*dir), len, color_len, direction_digit(*color_dir));
+    }
+    return res;
+}
+
+#[cfg(all(test, feature = "reference"))]
+pub fn generate_small(rng: &mut Rng, size: usize) -> String {
+    // colors as short as the first plan's meters, so that the reference can flood them too
+    return generate_plans(rng, size, 9);
+}
+
+impl Generator for Day18 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // up to 5 hexadecimal digits for the colors' lengths
+        let coarse = (size/4).max(1) as i64;
+        return generate_plans(rng, size, 0xfffff / (4*coarse));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day18::parse(&Day18::generate(&mut Rng::new(seed), 12)).unwrap();
+            // at least the trench around the lagoon
+            let trench: usize = input.instructions.iter().map(|(_, len)| len).sum();
+            assert!(Day18::part1(&input) >= trench);
+            Day18::part2(&input);
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::ops::Range;
+use aoc_common::{ParseError, Solution};
+use aoc_common::geometry::{BoundingBox, Direction, Point};
+use aoc_common::pipe::PipeType;
+use aoc_common::parse::lines;
+
+mod gen;
+#[cfg(feature = "reference")]
+mod reference;
+mod render;
+
+
+#[derive(Clone)]
+struct CompressedVec<T> {
+    data: Vec<(T,usize)>,
+}
+
+impl<T: Clone> CompressedVec<T> {
+    fn default() -> CompressedVec<T> {
+        CompressedVec{data:vec![]}
+    }
+
+    fn push_multi(&mut self, val: T, count: usize) {
+        self.data.push( (val,count) );
+    }
+
+    fn get_compressed_index(&self, index: usize) -> (usize,usize) {
+        // get the index of the segment and the observed index of the start of that segment
+        // such that the segment contains the asked 'index'
+        let mut current_index: usize = 0;
+        let mut segment_index: usize = self.data.len();
+        for (i,(_,seg_size)) in self.data.iter().enumerate() {
+            if current_index <= index && index < current_index + seg_size {
+                segment_index = i;
+                break;
+            }
+            current_index += seg_size;
+        }
+        return (segment_index, current_index);
+    }
+
+    fn get_ranges(&mut self, beg: usize, end:usize) -> Range<usize> {
+        // get a reference to a set of blocks that exactly cover the range [beg,end[
+        // split segments as needed
+
+        //println!("Get ranges [{beg},{end}[");
+
+        // get and split at beginning
+        let (seg_index_1,idx1) = self.get_compressed_index(beg);
+        let mut seg_beg_index = seg_index_1;
+        if idx1 != beg {
+            //println!("Splitting blockidx {} starting at {} of len {}, cut before {} (beg)", seg_beg_index, idx1, self.data.get(seg_beg_index).unwrap().1, beg);
+            // need to create a new block
+            let value = self.data.get_mut(seg_beg_index).unwrap().0.clone();
+            let newblock_len = beg-idx1;
+            self.data.get_mut(seg_beg_index).unwrap().1 -= newblock_len;
+            self.data.insert(seg_beg_index, (value, newblock_len));
+            seg_beg_index += 1;
+        }
+
+        // get and split at end
+        let (seg_index_2,idx2) = self.get_compressed_index(end);
+        let mut seg_end_index = seg_index_2;
+        if idx2 != end {
+            //println!("Splitting blockidx {} starting at {} of len {}, cut before {} (end)", seg_end_index, idx2, self.data.get(seg_end_index).unwrap().1, end);
+            // need to create a new block
+            let value = self.data.get(seg_end_index).unwrap().0.clone();
+            let newblock_len = end-idx2;
+            self.data.get_mut(seg_end_index).unwrap().1 -= newblock_len;
+            self.data.insert(seg_end_index, (value, newblock_len));
+            seg_end_index += 1;
+        }
+
+        return seg_beg_index..seg_end_index;
+    }
+
+    fn set_range(&mut self, beg: usize, end:usize, value: T) {
+        let range = self.get_ranges(beg, end);
+        for (val,_) in self.data[range].iter_mut() {
+            *val = value.clone();
+        }
+    }
+}
+
+struct PipeMap {
+    data: CompressedVec<CompressedVec<PipeType>>,
+}
+
+impl PipeMap {
+    fn empty(width: usize, height: usize) -> PipeMap {
+        //println!("Create map of size {width} {height}");
+        let mut line: CompressedVec<PipeType> = CompressedVec::default();
+        line.push_multi(PipeType::None, width);
+
+        let mut col: CompressedVec<CompressedVec<PipeType>> = CompressedVec::default();
+        col.push_multi(line, height);
+
+        return PipeMap{data:col};
+    }
+
+    fn get(&mut self, x: usize, y: usize) -> Option<&mut PipeType> {
+        // get single cell at x,y
+        //println!("Get y={} line", y);
+        let ry = self.data.get_ranges(y, y+1).start;
+        let (line,_) = self.data.data.get_mut(ry)?;
+        //println!("Get x={} cell", x);
+        let rx = line.get_ranges(x, x+1).start;
+        let (cell,_) = line.data.get_mut(rx)?;
+        return Some(cell);
+    }
+
+    fn set_range(&mut self, beg: (usize,usize), end: (usize,usize), value: PipeType) {
+        // set a rectangle [beg,end[ to 'value'
+        let ranges_y = self.data.get_ranges(beg.1, end.1);
+        for ry in ranges_y {
+            let (line,_) = self.data.data.get_mut(ry).unwrap();
+            line.set_range(beg.0, end.0, value);
+            /*
+            let ranges_x = line.get_ranges(beg.0, end.0);
+            for rx in ranges_x {
+                line.data.get_mut(rx).unwrap().0 = value;
+            }
+            */
+        }
+    }
+}
+
+
+fn get_dig_area(instructions: &Vec<(Direction,usize)>) -> usize {
+    // the map is stored bottom-up: its first coordinate is the column, the second one grows upwards
+    let mut bounds = BoundingBox::around(Point::new(0, 0));
+    {
+        let mut current = Point::new(0, 0);
+        for (dir,len) in instructions.iter() {
+            current = current.step_n(*dir, *len as i64);
+            bounds.extend(current);
+        }
+    }
+
+    //println!("Found ranges: {:?} {:?}", bounds.min, bounds.max);
+
+    let mut map: PipeMap = PipeMap::empty(bounds.width() as usize, bounds.height() as usize);
+
+    let mut current: (usize,usize) = (-bounds.min.col as usize, bounds.max.row as usize);
+    let mut prev_dir: Option<Direction> = None;
+    for (dir,len) in instructions.iter() {
+        //println!("Instruction: {:?} for {}", dir, len);
+        if *dir == Direction::Left {
+            if prev_dir.is_some() {
+                *map.get(current.0,current.1).unwrap() = match prev_dir.unwrap().reverse() {
+                    Direction::Top => PipeType::BendUL,
+                    Direction::Bottom => PipeType::BendDL,
+                    _ => PipeType::None,
+                };
+            }
+            if *len > 1 {
+                map.set_range((current.0-*len+1,current.1), (current.0,current.1+1), PipeType::Horizontal);
+            }
+            current = (current.0 - len,current.1);
+        } else if *dir == Direction::Right {
+            if prev_dir.is_some() {
+                *map.get(current.0,current.1).unwrap() = match prev_dir.unwrap().reverse() {
+                    Direction::Top => PipeType::BendUR,
+                    Direction::Bottom => PipeType::BendDR,
+                    _ => PipeType::None,
+                };
+            }
+            if *len > 1 {
+                map.set_range((current.0+1,current.1), (current.0+*len,current.1+1), PipeType::Horizontal);
+            }
+            current = (current.0 + len,current.1);
+        } else if *dir == Direction::Top {
+            if prev_dir.is_some() {
+                *map.get(current.0,current.1).unwrap() = match prev_dir.unwrap().reverse() {
+                    Direction::Left => PipeType::BendUL,
+                    Direction::Right => PipeType::BendUR,
+                    _ => PipeType::None,
+                };
+            }
+            if *len > 1 {
+                map.set_range((current.0,current.1+1), (current.0+1,current.1+*len), PipeType::Vertical);
+            }
+            current = (current.0,current.1 + len);
+        } else {
+            if prev_dir.is_some() {
+                *map.get(current.0,current.1).unwrap() = match prev_dir.unwrap().reverse() {
+                    Direction::Left => PipeType::BendDL,
+                    Direction::Right => PipeType::BendDR,
+                    _ => PipeType::None,
+                };
+            }
+            if *len > 1 {
+                map.set_range((current.0,current.1-*len+1), (current.0+1,current.1), PipeType::Vertical);
+            }
+            current = (current.0,current.1 - len);
+        }
+        prev_dir = Some(*dir);
+    }
+
+    *map.get(current.0,current.1).unwrap() = match (instructions.get(0).unwrap().0, prev_dir.unwrap().reverse()) {
+        (Direction::Left, Direction::Top) => PipeType::BendUL,
+        (Direction::Top, Direction::Left) => PipeType::BendUL,
+        (Direction::Right, Direction::Top) => PipeType::BendUR,
+        (Direction::Top, Direction::Right) => PipeType::BendUR,
+        (Direction::Left, Direction::Bottom) => PipeType::BendDL,
+        (Direction::Bottom, Direction::Left) => PipeType::BendDL,
+        (Direction::Bottom, Direction::Right) => PipeType::BendDR,
+        (Direction::Right, Direction::Bottom) => PipeType::BendDR,
+        _ => PipeType::None,
+    };
+
+    let mut area = 0;
+    for (line, line_height) in map.data.data.iter() {
+        let mut in_top = false; // is the top right corner in area
+        let mut in_bot = false; // is the bottom right corner in area
+        for (pipe, pipe_width) in line.data.iter() {
+            if *pipe != PipeType::None {
+                if *pipe == PipeType::Vertical {
+                    in_top = !in_top;
+                    in_bot = !in_bot;
+                } else if *pipe == PipeType::Horizontal {
+                    // don't change anything
+                } else if *pipe == PipeType::BendUR {
+                    // no wall -> on wall
+                    in_top = !in_top;
+                } else if *pipe == PipeType::BendDR {
+                    // no wall -> on wall
+                    in_bot = !in_bot;
+                } else if *pipe == PipeType::BendUL {
+                    // on wall -> no wall
+                    in_top = !in_top;
+                } else if *pipe == PipeType::BendDL {
+                    // on wall -> no wall
+                    in_bot = !in_bot;
+                }
+                area += line_height*pipe_width;
+            } else {
+                if in_top { // !is_wall -> (in_top == in_bot)
+                    area += line_height*pipe_width;
+                }
+            }
+        }
+    }
+
+    return area;
+}
+
+pub struct DigPlan {
+    instructions: Vec<(Direction,usize)>,
+    instructions2: Vec<(Direction,usize)>,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = DigPlan;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<DigPlan, ParseError> {
+        let mut instructions: Vec<(Direction,usize)> = vec![];
+        let mut instructions2: Vec<(Direction,usize)> = vec![];
+
+        for line in lines(contents) {
+            if line.len() == 0 {
+                continue;
+            }
+
+            let mut line_it = line.text.split(' ');
+            let (dir_str, len_str, color_str) = match (line_it.next(), line_it.next(), line_it.next(), line_it.next()) {
+                (Some(d), Some(l), Some(c), None) => (d, l, c),
+                _ => return Err(line.error_line("expected `<direction> <length> (#<color>)`")),
+            };
+
+            {
+                let direction = match (dir_str.len(), dir_str.chars().next().and_then(Direction::from_char)) {
+                    (1, Some(direction)) => direction,
+                    _ => return Err(line.error(dir_str, "expected a direction among `R`, `D`, `L` and `U`")),
+                };
+                let len = line.number::<usize>(len_str)?;
+                instructions.push( (direction,len) );
+            }
+
+            {
+                if color_str.len() != 9 || !color_str.starts_with("(#") || !color_str.ends_with(')') {
+                    return Err(line.error(color_str, "expected a color like `(#70c710)`"));
+                }
+                let hexa = &color_str[2..8];
+                if !hexa.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(line.error(hexa, "expected 6 hexadecimal digits"));
+                }
+                let direction = match hexa.chars().next_back().unwrap() {
+                    '0' => Direction::Right,
+                    '1' => Direction::Bottom,
+                    '2' => Direction::Left,
+                    '3' => Direction::Top,
+                    _ => return Err(line.error(&hexa[5..], "expected the last digit of the color to be a direction, from 0 to 3")),
+                };
+                let len = usize::from_str_radix(&hexa[..5], 16).unwrap();
+                instructions2.push( (direction,len) );
+            }
+        }
+
+        return Ok(DigPlan{instructions, instructions2});
+    }
+
+    fn part1(plan: &DigPlan) -> usize {
+        get_dig_area(&plan.instructions)
+    }
+
+    fn part2(plan: &DigPlan) -> usize {
+        get_dig_area(&plan.instructions2)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day18::parse("R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)").unwrap();
+        assert_eq!(Day18::part1(&input), 62);
+        assert_eq!(Day18::part2(&input), 952408144115);
+    }
+
+    fuzz_parse!(Day18);
+}
@@ -0,0 +1,73 @@
+use aoc_common::reference::Reference;
+use aoc_common::geometry::{BoundingBox, Direction, Point};
+use aoc_common::grid::Grid;
+use crate::{Day18, DigPlan};
+
+fn trench(instructions: &Vec<(Direction,usize)>) -> Vec<Point> {
+    // every meter dug, from the start back to it
+    let mut res = vec![Point::new(0, 0)];
+    for (dir, len) in instructions {
+        for _ in 0..*len {
+            let next = res.last().unwrap().step(*dir);
+            res.push(next);
+        }
+    }
+    return res;
+}
+
+fn flood_area(instructions: &Vec<(Direction,usize)>) -> usize {
+    /*
+    Draws the trench on a grid with a free border around it,
+    fills the outside from a corner and counts the rest.
+    */
+    let trench = trench(instructions);
+    let mut bounds = BoundingBox::around(trench[0]);
+    for p in &trench {
+        bounds.extend(*p);
+    }
+    let origin = Point::new(bounds.min.row - 1, bounds.min.col - 1);
+    let mut map = Grid::from_fn(bounds.height() as usize + 2, bounds.width() as usize + 2, |_, _| false);
+    for p in &trench {
+        map[((p.row - origin.row) as usize, (p.col - origin.col) as usize)] = true;
+    }
+
+    let mut outside = 0;
+    let mut stack: Vec<(usize,usize)> = vec![(0, 0)];
+    map[(0, 0)] = true;
+    while let Some((x, y)) = stack.pop() {
+        outside += 1;
+        for (nx, ny) in [(x+1, y), (x, y+1), (x.wrapping_sub(1), y), (x, y.wrapping_sub(1))] {
+            if nx < map.height() && ny < map.width() && !map[(nx, ny)] {
+                map[(nx, ny)] = true;
+                stack.push((nx, ny));
+            }
+        }
+    }
+    return map.height()*map.width() - outside;
+}
+
+impl Reference for Day18 {
+    fn reference_part1(plan: &DigPlan) -> usize {
+        flood_area(&plan.instructions)
+    }
+
+    fn reference_part2(plan: &DigPlan) -> usize {
+        flood_area(&plan.instructions2)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::reference::first_divergence_with;
+    use crate::gen::generate_small;
+
+    #[test]
+    fn test_matches_reference() {
+        // the colors of the generated plans are far too big to flood
+        if let Some(divergence) = first_divergence_with::<Day18>(0..30, 12, generate_small) {
+            panic!("{}", divergence);
+        }
+    }
+}
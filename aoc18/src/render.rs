@@ -0,0 +1,51 @@
+use aoc_common::geometry::{BoundingBox, Point};
+use aoc_common::render::{Color, Picture, Render};
+use crate::{Day18, DigPlan};
+
+const GROUND: Color = Color::new(120, 90, 60);
+const LAGOON: Color = Color::new(60, 140, 220);
+const TRENCH: Color = Color::new(20, 40, 90);
+
+impl Render for Day18 {
+    fn render(plan: &DigPlan) -> Picture {
+        // the lagoon of the first plan, the second one is far too big to be drawn cell by cell
+        let mut corners = vec![Point::new(0, 0)];
+        for (dir, len) in plan.instructions.iter() {
+            let next = corners.last().unwrap().step_n(*dir, *len as i64);
+            corners.push(next);
+        }
+        let mut bounds = BoundingBox::around(corners[0]);
+        for corner in corners.iter() {
+            bounds.extend(*corner);
+        }
+        let corners: Vec<Point> = corners.iter().map(|p| Point::new(p.row - bounds.min.row, p.col - bounds.min.col)).collect();
+
+        let mut picture = Picture::new(bounds.height() as usize, bounds.width() as usize, GROUND);
+        picture.fill_polygon(&corners, LAGOON);
+        for pair in corners.windows(2) {
+            let mut current = pair[0];
+            while current != pair[1] {
+                picture.cells[current] = TRENCH;
+                current = Point::new(current.row + (pair[1].row - current.row).signum(), current.col + (pair[1].col - current.col).signum());
+            }
+        }
+        return picture;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_render() {
+        let input = Day18::parse("R 6 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nD 2 (#d2c081)\nR 2 (#59c680)\nD 2 (#411b91)\nL 5 (#8ceee2)\nU 2 (#caa173)\nL 1 (#1b58a2)\nU 2 (#caa171)\nR 2 (#7807d2)\nU 3 (#a77fa3)\nL 2 (#015232)\nU 2 (#7a21e3)").unwrap();
+        let picture = Day18::render(&input);
+        assert_eq!((picture.cells.height(), picture.cells.width()), (10, 7));
+        let dug = picture.cells.iter().filter(|(_, c)| **c != GROUND).count();
+        assert_eq!(dug, Day18::part1(&input));
+        assert_eq!(picture.cells.iter().filter(|(_, c)| **c == TRENCH).count(), 38);
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use aoc_common::Generator;
+use aoc_common::gen::random_word;
+use aoc_common::rng::Rng;
+use crate::Day19;
+
+fn random_destination(rng: &mut Rng, pending: &mut Vec<String>, used: &mut HashSet<String>, budget: &mut usize) -> String {
+    // a new workflow while the budget lasts, otherwise accepted or rejected
+    if *budget > 0 && rng.chance(0.6) {
+        *budget -= 1;
+        loop {
+            let len = rng.range(2, 3) as usize;
+            let name = random_word(rng, len, "abcdefghijklmnopqrstuvwxyz");
+            if used.insert(name.clone()) {
+                pending.push(name.clone());
+                return name;
+            }
+        }
+    }
+    return if rng.chance(0.5) { "A".to_string() } else { "R".to_string() };
+}
+
+impl Generator for Day19 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        The workflows form a tree from `in`: each one is the destination of a single step,
+        so that no part can loop forever.
+        */
+        let count = size.max(1);
+        let mut budget = count - 1;
+        let mut used: HashSet<String> = HashSet::from(["in".to_string()]);
+        let mut pending: Vec<String> = vec!["in".to_string()];
+        let mut workflows: Vec<String> = vec![];
+        while let Some(name) = pending.pop() {
+            let mut steps: Vec<String> = vec![];
+            for _ in 0..rng.range(1, 4) {
+                let variable = *rng.choose(&['x', 'm', 'a', 's']);
+                let operator = if rng.chance(0.5) { '<' } else { '>' };
+                let destination = random_destination(rng, &mut pending, &mut used, &mut budget);
+                steps.push(format!("{}{}{}:{}", variable, operator, rng.range(1, 4000), destination));
+            }
+            steps.push(random_destination(rng, &mut pending, &mut used, &mut budget));
+            workflows.push(format!("{}{{{}}}", name, steps.join(",")));
+        }
+        rng.shuffle(&mut workflows);
+
+        let parts: Vec<String> = (0..count)
+            .map(|_| format!("{{x={},m={},a={},s={}}}", rng.range(1, 4000), rng.range(1, 4000), rng.range(1, 4000), rng.range(1, 4000)))
+            .collect();
+
+        return workflows.join("\n") + "\n\n" + &parts.join("\n") + "\n";
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day19::parse(&Day19::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert_eq!(input.parts.len(), 20);
+            Day19::part1(&input);
+            assert!(Day19::part2(&input) <= 4000usize.pow(4));
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::HashMap;
+use aoc_common::{ParseError, Solution};
+use aoc_common::interval::{self, Interval};
+use aoc_common::parse::{blocks, Line};
+
+mod gen;
+#[cfg(feature = "reference")]
+mod reference;
+
+
+#[derive(Debug,PartialEq)]
+enum Operator {
+    IsGreaterThan,
+    IsLessThan,
+}
+
+fn varname_to_index(c: &char) -> usize {
+    match c {
+        'x' => 0,
+        'm' => 1,
+        'a' => 2,
+        _   => 3, //s
+    }
+}
+
+fn symbol_to_operator(c: &char) -> Operator {
+    match c {
+        '<' => Operator::IsLessThan,
+        _ => Operator::IsGreaterThan,
+    }
+}
+
+#[derive(Debug)]
+struct Step {
+    variable: usize,
+    operator: Operator,
+    constant: usize,
+    dest_flow: String,
+}
+
+#[derive(Debug)]
+struct Workflow {
+    steps: Vec<Step>,
+    fallback: String,
+}
+
+fn parse_workflow<'a>(line: &Line<'a>) -> Result<(String, Workflow, Vec<&'a str>), ParseError> {
+    // also returns the names of the destination flows, to check that they exist
+    let s = line.text;
+    let (name, rest) = s.split_once('{').ok_or_else(|| line.error_line("expected `<name>{<steps>}`, missing `{`"))?;
+    let inside = rest.strip_suffix('}').ok_or_else(|| line.error(rest, "expected the steps to end with `}`"))?;
+
+    let mut steps:Vec<Step> = vec![];
+    let mut destinations: Vec<&str> = vec![];
+    let mut lastname: Option<&str> = None;
+    for part in inside.split(',') {
+        if lastname.is_some() {
+            return Err(line.error(part, "no step can follow the fallback flow"));
+        }
+        if part.contains(':') {
+            let (condition, dest) = part.split_once(':').unwrap();
+            let mut condition_chars = condition.chars();
+            let variable = match condition_chars.next() {
+                Some(c) if "xmas".contains(c) => c,
+                _ => return Err(line.error(part, "expected a condition on `x`, `m`, `a` or `s`")),
+            };
+            let operator = match condition_chars.next() {
+                Some(c) if c == '<' || c == '>' => c,
+                _ => return Err(line.error(condition, "expected `<` or `>` after the variable")),
+            };
+            steps.push(Step{
+                variable: varname_to_index(&variable),
+                operator: symbol_to_operator(&operator),
+                constant: line.number::<usize>(&condition[2..])?,
+                dest_flow: dest.to_string(),
+            });
+            destinations.push(dest);
+        } else {
+            lastname = Some(part);
+            destinations.push(part);
+        }
+    }
+
+    let fallback = lastname.ok_or_else(|| line.error(inside, "expected a fallback flow as the last step"))?;
+    let wf = Workflow{
+        steps: steps,
+        fallback: fallback.to_string(),
+    };
+
+    return Ok((name.to_string(), wf, destinations));
+}
+
+fn is_part_accepted(part: &[usize;4], flows: &HashMap<String,Workflow>) -> bool {
+    let mut current_flow: String = "in".to_string();
+
+    while current_flow != "R" && current_flow != "A" {
+        let flow = flows.get(&current_flow).unwrap();
+        let mut moved: bool = false;
+        for step in &flow.steps {
+            let matches = match step.operator {
+                Operator::IsLessThan => part[step.variable] < step.constant,
+                Operator::IsGreaterThan => part[step.variable] > step.constant,
+            };
+            if matches {
+                moved = true;
+                current_flow = step.dest_flow.clone();
+                break;
+            }
+        }
+
+        if !moved {
+            current_flow = flow.fallback.clone();
+        }
+    }
+
+    return current_flow == "A";
+}
+
+// the ranges of x, m, a and s
+type PartRange = interval::Cuboid<usize,4>;
+
+fn split_range(range: &PartRange, variable: &usize, constant: &usize, operator: &Operator) -> (Option<PartRange>, Option<PartRange>) {
+    // split a block into a (valid range, invalid range)
+    match operator {
+        // [Off, Cst[ is valid
+        Operator::IsLessThan => range.split(*variable, *constant),
+        // [Cst+1, End[ is valid
+        Operator::IsGreaterThan => {
+            let (below, above) = range.split(*variable, *constant+1);
+            (above, below)
+        },
+    }
+}
+
+fn resolve_range(flows: &HashMap<String,Workflow>) -> Vec<PartRange> {
+    let mut ranges: HashMap<String,Vec<PartRange>> = HashMap::new();
+    let mut success: Vec<PartRange> = vec![];
+
+    const FULL_RANGE: PartRange = PartRange{axes: [Interval{start: 1, end: 4001}; 4]};
+    ranges.insert("in".to_string(), vec![FULL_RANGE]);
+    while ranges.keys().len() > 0 {
+        // pop first
+        let first_key: String = ranges.keys().next().unwrap().clone();
+        let (flowname, waiting_ranges) = ranges.remove_entry(&first_key).unwrap();
+
+        //println!("Applying flow {}", &flowname);
+        //dbg!(&waiting_ranges);
+
+        // apply the flow
+        let mut after_flow: Vec<(String,PartRange)> = vec![];
+        for to_sort in waiting_ranges {
+            let mut remaining = Some(to_sort);
+            let flow = flows.get(&flowname).unwrap();
+            let mut i_opt = None;
+            for step in &flow.steps {
+                let valid_invalid = split_range(&remaining.unwrap(), &step.variable, &step.constant, &step.operator);
+                let v_opt = valid_invalid.0;
+                i_opt = valid_invalid.1;
+                if v_opt.is_some() {
+                    after_flow.push( (step.dest_flow.clone(),v_opt.unwrap()) );
+                }
+                remaining = i_opt;
+                if i_opt.is_none() {
+                    break;
+                }
+            }
+            if i_opt.is_some() {
+                after_flow.push( (flow.fallback.clone(),i_opt.unwrap()) );
+            }
+        }
+
+        //println!("Result:");
+        //dbg!(&after_flow);
+
+        // insert the results
+        for (flowname, to_add) in after_flow {
+            if flowname == "A" {
+                success.push(to_add);
+            } else if flowname == "R" {
+                //drop
+            } else {
+                ranges.entry(flowname).or_insert(vec![]).push(to_add);
+            }
+        }
+    }
+
+    return success;
+}
+
+pub struct System {
+    workflows: HashMap<String,Workflow>,
+    parts: Vec<[usize;4]>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = System;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<System, ParseError> {
+        let blocks = blocks(contents);
+        if blocks.len() == 0 {
+            return Err(ParseError::new("expected the workflows, then an empty line and the parts"));
+        }
+
+        let mut workflows: HashMap<String,Workflow> = HashMap::new();
+        let mut destinations: Vec<(Line, &str)> = vec![];
+        for line in &blocks[0] {
+            let (name,wf,dests) = parse_workflow(line)?;
+            for dest in dests {
+                destinations.push( (*line, dest) );
+            }
+
+            workflows.insert(name, wf);
+        }
+
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new("no `in` workflow to start from"));
+        }
+        for (line, dest) in destinations {
+            if dest != "A" && dest != "R" && !workflows.contains_key(dest) {
+                return Err(line.error(dest, &format!("workflow `{}` is never defined", dest)));
+            }
+        }
+
+        //dbg!(&workflows);
+
+        let mut parts: Vec<[usize;4]> = vec![];
+        for line in blocks[1..].iter().flatten() {
+            let mut partvar: [usize;4] = [0, 0, 0, 0];
+
+            let inside = line.strip_prefix('{').and_then(|s| s.strip_suffix('}')).ok_or_else(|| line.error_line("expected `{x=<n>,m=<n>,a=<n>,s=<n>}`"))?;
+            for var in inside.split(',') {
+                match var.split_once('=') {
+                    Some((name, value)) if name.len() == 1 && "xmas".contains(name) => {
+                        partvar[varname_to_index(&name.chars().next().unwrap())] = line.number::<usize>(value)?;
+                    },
+                    _ => return Err(line.error(var, "expected `<x|m|a|s>=<n>`")),
+                }
+            }
+
+            parts.push(partvar);
+        }
+
+        //dbg!(&parts);
+
+        return Ok(System{workflows, parts});
+    }
+
+    fn part1(system: &System) -> usize {
+        let mut res: usize = 0;
+        for part in &system.parts {
+            if is_part_accepted(part, &system.workflows) {
+                res += part.iter().sum::<usize>();
+            }
+        }
+        return res;
+    }
+
+    fn part2(system: &System) -> usize {
+        return resolve_range(&system.workflows).iter().map(|range| range.volume()).sum();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day19::parse("px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}").unwrap();
+        assert_eq!(Day19::part1(&input), 19114);
+        assert_eq!(Day19::part2(&input), 167409079868000);
+    }
+
+    fn full_range() -> PartRange {
+        PartRange::new([Interval::new(1, 4001); 4])
+    }
+
+    #[test]
+    fn test_split_range_less_than() {
+        let (valid, invalid) = split_range(&full_range(), &0, &2006, &Operator::IsLessThan);
+        let (valid, invalid) = (valid.unwrap(), invalid.unwrap());
+        assert_eq!((valid.axes[0], valid.axes[0].len()), (Interval::new(1, 2006), 2005));
+        assert_eq!((invalid.axes[0], invalid.axes[0].len()), (Interval::new(2006, 4001), 1995));
+        assert_eq!(valid.axes[1].len(), 4000);
+    }
+
+    #[test]
+    fn test_split_range_greater_than() {
+        let (valid, invalid) = split_range(&full_range(), &1, &2090, &Operator::IsGreaterThan);
+        let (valid, invalid) = (valid.unwrap(), invalid.unwrap());
+        assert_eq!((valid.axes[1], valid.axes[1].len()), (Interval::new(2091, 4001), 1910));
+        assert_eq!((invalid.axes[1], invalid.axes[1].len()), (Interval::new(1, 2091), 2090));
+    }
+
+    #[test]
+    fn test_split_range_untouched() {
+        let (valid, invalid) = split_range(&full_range(), &2, &5000, &Operator::IsLessThan);
+        assert!(valid.is_some() && invalid.is_none());
+        let (valid, invalid) = split_range(&full_range(), &3, &5000, &Operator::IsGreaterThan);
+        assert!(valid.is_none() && invalid.is_some());
+    }
+
+    #[test]
+    fn test_parse_missing_brace() {
+        let e = Day19::parse("in:A,R}\n\n").err().unwrap();
+        assert_eq!(e.line, 1);
+        assert!(e.message.contains("missing `{`"));
+    }
+
+    #[test]
+    fn test_parse_undefined_workflow() {
+        let e = Day19::parse("in{x<10:abc,R}\n\n{x=1,m=2,a=3,s=4}\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (1, 9, 3));
+    }
+
+    #[test]
+    fn test_parse_bad_part() {
+        let e = Day19::parse("in{x<10:A,R}\n\n{x=1,m=2,q=3,s=4}\n").err().unwrap();
+        assert_eq!((e.line, e.column), (3, 10));
+    }
+
+    fuzz_parse!(Day19);
+}
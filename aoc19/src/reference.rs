@@ -0,0 +1,59 @@
+use aoc_common::reference::Reference;
+use crate::{Day19, Operator, System, is_part_accepted};
+
+impl Reference for Day19 {
+    fn reference_part1(system: &System) -> usize {
+        // running the workflows on each part is already the plain way
+        system.parts.iter().filter(|part| is_part_accepted(part, &system.workflows)).map(|part| part.iter().sum::<usize>()).sum()
+    }
+
+    fn reference_part2(system: &System) -> usize {
+        /*
+        The rating values between two constants of the workflows all go the same way,
+        so trying one of each cell of these cuts covers every part.
+        */
+        let mut cuts: [Vec<usize>; 4] = std::array::from_fn(|_| vec![1, 4001]);
+        for workflow in system.workflows.values() {
+            for step in &workflow.steps {
+                let cut = match step.operator {
+                    Operator::IsLessThan => step.constant,
+                    Operator::IsGreaterThan => step.constant + 1,
+                };
+                cuts[step.variable].push(cut.clamp(1, 4001));
+            }
+        }
+        for axis in cuts.iter_mut() {
+            axis.sort();
+            axis.dedup();
+        }
+
+        let mut res = 0;
+        for x in cuts[0].windows(2) {
+            for m in cuts[1].windows(2) {
+                for a in cuts[2].windows(2) {
+                    for s in cuts[3].windows(2) {
+                        if is_part_accepted(&[x[0], m[0], a[0], s[0]], &system.workflows) {
+                            res += (x[1]-x[0]) * (m[1]-m[0]) * (a[1]-a[0]) * (s[1]-s[0]);
+                        }
+                    }
+                }
+            }
+        }
+        return res;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::reference::first_divergence;
+
+    #[test]
+    fn test_matches_reference() {
+        // a few workflows, at most a few ten thousand cells
+        if let Some(divergence) = first_divergence::<Day19>(0..20, 3) {
+            panic!("{}", divergence);
+        }
+    }
+}
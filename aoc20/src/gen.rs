@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+use aoc_common::Generator;
+use aoc_common::gen::random_word;
+use aoc_common::rng::Rng;
+use crate::Day20;
+
+fn new_name(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    loop {
+        let name = random_word(rng, 2, "abcdefghijklmnopqrstuvwxyz");
+        if name != "rx" && used.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+fn module_line(rng: &mut Rng, module: &str, outputs: &Vec<String>) -> String {
+    let mut outputs = outputs.clone();
+    rng.shuffle(&mut outputs);
+    return format!("{} -> {}", module, outputs.join(", "));
+}
+
+impl Generator for Day20 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Like the puzzle, the broadcaster drives a few binary counters of flip-flops.
+        The conjunction of a counter reads the bits set in its period, and adds the others
+        plus one when they are all set: the counter overflows back to 0 on the press
+        reaching the period. Periods are odd so that this press only flips the first bit,
+        the counters all reach their inverters at the same time then.
+        */
+        let bits = (size/2).clamp(2, 12);
+        let counter_count = rng.range(2, 4) as usize;
+
+        let mut used: HashSet<String> = HashSet::new();
+        let last = new_name(rng, &mut used);
+        let mut res: Vec<String> = vec![];
+        let mut starts: Vec<String> = vec![];
+        let mut inverters: Vec<String> = vec![];
+        for _ in 0..counter_count {
+            let period = (1 << (bits-1)) | rng.below(1 << (bits-1)) | 1;
+            let flip_flops: Vec<String> = (0..bits).map(|_| new_name(rng, &mut used)).collect();
+            let (conjunction, inverter) = (new_name(rng, &mut used), new_name(rng, &mut used));
+
+            let mut reset = vec![flip_flops[0].clone(), inverter.clone()];
+            for (i, flip_flop) in flip_flops.iter().enumerate() {
+                let mut outputs: Vec<String> = flip_flops.get(i+1).into_iter().cloned().collect();
+                if period & (1 << i) != 0 {
+                    outputs.push(conjunction.clone());
+                } else {
+                    reset.push(flip_flop.clone());
+                }
+                res.push(module_line(rng, &format!("%{}", flip_flop), &outputs));
+            }
+            res.push(module_line(rng, &format!("&{}", conjunction), &reset));
+            res.push(module_line(rng, &format!("&{}", inverter), &vec![last.clone()]));
+
+            starts.push(flip_flops[0].clone());
+            inverters.push(inverter);
+        }
+        res.push(module_line(rng, "broadcaster", &starts));
+        res.push(module_line(rng, &format!("&{}", last), &vec!["rx".to_string()]));
+
+        rng.shuffle(&mut res);
+        return res.join("\n") + "\n";
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+    use crate::{State, press_button, presses_to_rx};
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day20::parse(&Day20::generate(&mut Rng::new(seed), 20)).unwrap();
+            Day20::part1(&input);
+            // 10 bits, every period has its top bit set
+            assert!(presses_to_rx(&input).unwrap() >= 512);
+        }
+    }
+
+    #[test]
+    fn test_generated_rx_pulse() {
+        // small counters, pressed until rx actually gets its low pulse
+        for seed in 0..10 {
+            let input = Day20::parse(&Day20::generate(&mut Rng::new(seed), 6)).unwrap();
+            let rx = input.index_of("rx").unwrap();
+            let mut state = State::new(&input);
+            let mut presses = 0;
+            let mut done = false;
+            while !done {
+                presses += 1;
+                press_button(&input, &mut state, |pulse| done |= pulse.to == rx && !pulse.high);
+            }
+            assert_eq!(presses_to_rx(&input), Ok(presses));
+        }
+    }
+}
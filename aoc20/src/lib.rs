@@ -0,0 +1,288 @@
+use std::collections::{HashMap, VecDeque};
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+use aoc_common::numtheory::lcm;
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ModuleKind {
+    Broadcaster,
+    FlipFlop,    // %
+    Conjunction, // &
+    Untyped,     // only ever a destination, like `output` or `rx`
+}
+
+#[derive(Debug)]
+struct Module {
+    kind: ModuleKind,
+    outputs: Vec<usize>,
+}
+
+pub struct Network {
+    names: Vec<String>,
+    modules: Vec<Module>,
+    inputs: Vec<Vec<usize>>, // the modules sending to each one, in the order of the conjunctions' memory
+    broadcaster: usize,
+}
+
+impl Network {
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+}
+
+const BUTTON: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Pulse {
+    from: usize,
+    to: usize,
+    high: bool,
+}
+
+struct State {
+    on: Vec<bool>, // flip-flops
+    memory: Vec<Vec<bool>>, // conjunctions, the last pulse received from each input
+}
+
+impl State {
+    fn new(network: &Network) -> State {
+        State{
+            on: vec![false; network.modules.len()],
+            memory: network.inputs.iter().map(|inputs| vec![false; inputs.len()]).collect(),
+        }
+    }
+}
+
+fn press_button(network: &Network, state: &mut State, mut on_pulse: impl FnMut(&Pulse)) {
+    // the pulses are handled in the order they are sent
+    let mut queue: VecDeque<Pulse> = VecDeque::new();
+    queue.push_back(Pulse{from: BUTTON, to: network.broadcaster, high: false});
+
+    while let Some(pulse) = queue.pop_front() {
+        on_pulse(&pulse);
+        let module = &network.modules[pulse.to];
+        let sent = match module.kind {
+            ModuleKind::Broadcaster => Some(pulse.high),
+            ModuleKind::FlipFlop => {
+                if pulse.high {
+                    None
+                } else {
+                    state.on[pulse.to] = !state.on[pulse.to];
+                    Some(state.on[pulse.to])
+                }
+            },
+            ModuleKind::Conjunction => {
+                let slot = network.inputs[pulse.to].iter().position(|i| *i == pulse.from).unwrap();
+                state.memory[pulse.to][slot] = pulse.high;
+                Some(!state.memory[pulse.to].iter().all(|h| *h))
+            },
+            ModuleKind::Untyped => None,
+        };
+
+        if let Some(high) = sent {
+            for to in &module.outputs {
+                queue.push_back(Pulse{from: pulse.to, to: *to, high});
+            }
+        }
+    }
+}
+
+fn parse_name<'a>(line: &Line, s: &'a str) -> Result<&'a str, ParseError> {
+    let name = s.trim();
+    if name.len() == 0 || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+        return Err(line.error(if name.len() > 0 { name } else { s }, &format!("expected a module name in lowercase letters or digits, found `{}`", name)));
+    }
+    return Ok(name);
+}
+
+fn module_index(names: &mut Vec<String>, indexes: &mut HashMap<String, usize>, name: &str) -> usize {
+    // modules get an index when first seen, as a destination or a definition
+    if let Some(i) = indexes.get(name) {
+        return *i;
+    }
+    names.push(name.to_string());
+    indexes.insert(name.to_string(), names.len()-1);
+    return names.len()-1;
+}
+
+fn presses_to_rx(network: &Network) -> Result<i64, String> {
+    /*
+    rx is fed by a single conjunction, which sends it a low pulse once all its inputs
+    sent it a high pulse during the same press. Each input sends one every n presses
+    (valid on input data, every one a counter resetting itself), so all of them do
+    on the lowest common multiple of their periods.
+    */
+    let rx = network.index_of("rx").ok_or("no module sends pulses to rx")?;
+    let feeder = match network.inputs[rx][..] {
+        [feeder] if network.modules[feeder].kind == ModuleKind::Conjunction => feeder,
+        _ => return Err("rx is not fed by a single conjunction".to_string()),
+    };
+    if network.inputs[feeder].len() == 0 {
+        // nothing ever pulses it, so it never sends anything to rx
+        return Err(format!("{} has no inputs", network.names[feeder]));
+    }
+
+    let mut periods: Vec<Option<i64>> = vec![None; network.inputs[feeder].len()];
+    let mut state = State::new(network);
+    let mut presses: i64 = 0;
+    while periods.iter().any(|p| p.is_none()) {
+        presses += 1;
+        if presses > 1_000_000 {
+            return Err(format!("some inputs of {} never send it a high pulse", network.names[feeder]));
+        }
+        press_button(network, &mut state, |pulse| {
+            if pulse.to == feeder && pulse.high {
+                let slot = network.inputs[feeder].iter().position(|i| *i == pulse.from).unwrap();
+                periods[slot].get_or_insert(presses);
+            }
+        });
+    }
+
+    return Ok(periods.iter().fold(1, |acc, p| lcm(&acc, &p.unwrap())));
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = Network;
+    type Answer1 = u64;
+    type Answer2 = MaybeAnswer<i64>;
+
+    fn parse(contents: &str) -> Result<Network, ParseError> {
+        let mut names: Vec<String> = vec![];
+        let mut indexes: HashMap<String, usize> = HashMap::new();
+        let mut definitions: Vec<(usize, ModuleKind, Vec<usize>)> = vec![];
+        let mut defined: HashMap<usize, Line> = HashMap::new();
+
+        for line in lines(contents) {
+            if line.len() == 0 {
+                continue;
+            }
+            let (module_str, dest_str) = line.split_once("->").ok_or_else(|| line.error_line("expected `<module> -> <destinations>`"))?;
+            let module_str = module_str.trim();
+
+            let (kind, name) = if let Some(name) = module_str.strip_prefix('%') {
+                (ModuleKind::FlipFlop, parse_name(&line, name)?)
+            } else if let Some(name) = module_str.strip_prefix('&') {
+                (ModuleKind::Conjunction, parse_name(&line, name)?)
+            } else if module_str == "broadcaster" {
+                (ModuleKind::Broadcaster, module_str)
+            } else {
+                return Err(line.error(module_str, "expected `broadcaster`, or a module name after `%` or `&`"));
+            };
+
+            let index = module_index(&mut names, &mut indexes, name);
+            if defined.contains_key(&index) {
+                return Err(line.error(name, &format!("module `{}` is already defined on line {}", name, defined[&index].number)));
+            }
+            defined.insert(index, line);
+
+            let mut outputs: Vec<usize> = vec![];
+            for dest in dest_str.split(',') {
+                let dest_name = parse_name(&line, dest)?;
+                outputs.push(module_index(&mut names, &mut indexes, dest_name));
+            }
+            definitions.push( (index, kind, outputs) );
+        }
+
+        let broadcaster = *indexes.get("broadcaster").ok_or_else(|| ParseError::new("no `broadcaster` module to send the button's pulses to"))?;
+
+        let mut modules: Vec<Module> = names.iter().map(|_| Module{kind: ModuleKind::Untyped, outputs: vec![]}).collect();
+        let mut inputs: Vec<Vec<usize>> = vec![vec![]; names.len()];
+        for (index, kind, outputs) in definitions {
+            for output in outputs.iter() {
+                inputs[*output].push(index);
+            }
+            modules[index] = Module{kind, outputs};
+        }
+
+        return Ok(Network{names, modules, inputs, broadcaster});
+    }
+
+    fn part1(network: &Network) -> u64 {
+        let mut state = State::new(network);
+        let (mut low, mut high) = (0, 0);
+        for _ in 0..1000 {
+            press_button(network, &mut state, |pulse| if pulse.high { high += 1 } else { low += 1 });
+        }
+        return low * high;
+    }
+
+    fn part2(network: &Network) -> MaybeAnswer<i64> {
+        presses_to_rx(network).into()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    #[test]
+    fn test_example() {
+        let input = Day20::parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a\n").unwrap();
+        assert_eq!(Day20::part1(&input), 32000000);
+    }
+
+    #[test]
+    fn test_example_output() {
+        let input = Day20::parse("broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output\n").unwrap();
+        assert_eq!(Day20::part1(&input), 11687500);
+        assert_eq!(Day20::part2(&input).to_string(), "no answer (no module sends pulses to rx)");
+    }
+
+    #[test]
+    fn test_part2_no_counters() {
+        let input = Day20::parse("broadcaster -> a\n%a -> rx\n").unwrap();
+        assert_eq!(presses_to_rx(&input), Err("rx is not fed by a single conjunction".to_string()));
+        // x never gets a pulse, so never sends one to c
+        let input = Day20::parse("broadcaster -> a\n%a -> c\n&x -> c\n&c -> rx\n").unwrap();
+        assert_eq!(presses_to_rx(&input), Err("some inputs of c never send it a high pulse".to_string()));
+        let input = Day20::parse("broadcaster -> a\n&fin -> rx\n&a -> b\n").unwrap();
+        assert_eq!(presses_to_rx(&input), Err("fin has no inputs".to_string()));
+    }
+
+    #[test]
+    fn test_part2_counters() {
+        // two counters of 3 flip-flops, resetting after 5 and 7 presses
+        let input = Day20::parse("broadcaster -> a0, b0
+%a0 -> a1, ca
+%a1 -> a2
+%a2 -> ca
+&ca -> a0, a1, ia
+&ia -> fin
+%b0 -> b1, cb
+%b1 -> b2, cb
+%b2 -> cb
+&cb -> b0, ib
+&ib -> fin
+&fin -> rx
+").unwrap();
+        assert_eq!(presses_to_rx(&input), Ok(35));
+    }
+
+    #[test]
+    fn test_parse_bad_kind() {
+        let e = Day20::parse("broadcaster -> a\n*a -> b\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (2, 1, 2));
+    }
+
+    #[test]
+    fn test_parse_twice_defined() {
+        let e = Day20::parse("broadcaster -> a\n%a -> b\n&a -> b\n").err().unwrap();
+        assert_eq!((e.line, e.column), (3, 2));
+        assert!(e.message.contains("line 2"));
+    }
+
+    #[test]
+    fn test_parse_no_broadcaster() {
+        assert!(Day20::parse("%a -> b\n").is_err());
+    }
+
+    fuzz_parse!(Day20);
+}
@@ -0,0 +1,14 @@
+use std::env;
+use aoc_common::Solution;
+use aoc_common::input::run_main;
+use aoc20::Day20;
+
+fn main() {
+    run_main::<Day20>(env::args().collect(), |input| {
+        let pulses = Day20::part1(input);
+        let presses = Day20::part2(input);
+
+        println!("Low pulses times high pulses: {pulses}");
+        println!("Presses before rx gets a low pulse: {presses}");
+    });
+}
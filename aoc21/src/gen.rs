@@ -0,0 +1,64 @@
+use aoc_common::Generator;
+use aoc_common::grid::Grid;
+use aoc_common::rng::Rng;
+use crate::Day21;
+
+fn random_garden(rng: &mut Rng, side: usize, rock_chance: f64) -> String {
+    /*
+    Like the puzzle, a square of odd side with the start in its middle, and the border,
+    the row and the column of the start free of rocks.
+    Rocks never touch each other, even diagonally: no plot is walled in, and none is
+    further from the start than its distance, which part 2 relies on.
+    */
+    let middle = side/2;
+    let mut rocks = Grid::new(side, side, false);
+    for row in 1..(side-1) {
+        for col in 1..(side-1) {
+            if row == middle || col == middle || !rng.chance(rock_chance) {
+                continue;
+            }
+            if rocks.neighbours8(row, col).all(|(r, c)| !rocks[(r, c)]) {
+                rocks[(row, col)] = true;
+            }
+        }
+    }
+    return Grid::from_fn(side, side, |row, col| {
+        if (row, col) == (middle, middle) { 'S' } else if rocks[(row, col)] { '#' } else { '.' }
+    }).display(|c| *c).to_string();
+}
+
+impl Generator for Day21 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        // 26501365 steps only end on the edge of a copy of the garden for a side of 131, size sets the rocks
+        return random_garden(rng, 131, (size as f64 / 60.0).min(0.5));
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+    use crate::{reachable, reachable_extrapolated};
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..3 {
+            let input = Day21::parse(&Day21::generate(&mut Rng::new(seed), 20)).unwrap();
+            assert!(Day21::part1(&input) > 0);
+            Day21::part2(&input);
+        }
+    }
+
+    #[test]
+    fn test_generated_extrapolated() {
+        // the extrapolation against the brute force, a few copies of the garden away
+        for seed in 0..10 {
+            let input = Day21::parse(&random_garden(&mut Rng::new(seed), 7, 0.5)).unwrap();
+            for k in 2..8 {
+                let steps = 3 + k*7;
+                assert_eq!(reachable_extrapolated(&input, steps), Ok(reachable(&input, steps, true) as u64));
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::{HashSet, VecDeque};
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+use aoc_common::grid::Grid;
+use aoc_common::parse::{grid_lines, Line};
+
+mod gen;
+
+
+const STEPS: usize = 64;
+const INFINITE_STEPS: usize = 26501365;
+
+pub struct Garden {
+    rocks: Grid<bool>,
+    start: (usize, usize),
+}
+
+impl Garden {
+    fn is_rock(&self, row: isize, col: isize) -> bool {
+        // the map repeats itself in every direction
+        let (height, width) = (self.rocks.height() as isize, self.rocks.width() as isize);
+        return self.rocks[(row.rem_euclid(height) as usize, col.rem_euclid(width) as usize)];
+    }
+}
+
+fn reachable(garden: &Garden, steps: usize, infinite: bool) -> usize {
+    /*
+    Breadth first search from the start. The elf can walk back and forth between
+    two plots, so a plot first reached in d steps is reachable in `steps` exactly
+    when d <= steps and d has the same parity.
+    Brute force on the infinite map: every plot of the diamond is visited.
+    */
+    let start = (garden.start.0 as isize, garden.start.1 as isize);
+    let mut seen: HashSet<(isize, isize)> = HashSet::from([start]);
+    let mut queue: VecDeque<((isize, isize), usize)> = VecDeque::from([(start, 0)]);
+    let mut res = 0;
+
+    while let Some(((row, col), dist)) = queue.pop_front() {
+        if dist % 2 == steps % 2 {
+            res += 1;
+        }
+        if dist == steps {
+            continue;
+        }
+        for (next_row, next_col) in [(row-1, col), (row+1, col), (row, col-1), (row, col+1)] {
+            if !infinite && !garden.rocks.contains(next_row, next_col) {
+                continue;
+            }
+            if !garden.is_rock(next_row, next_col) && seen.insert((next_row, next_col)) {
+                queue.push_back(((next_row, next_col), dist+1));
+            }
+        }
+    }
+    return res;
+}
+
+fn reachable_extrapolated(garden: &Garden, steps: usize) -> Result<u64, String> {
+    /*
+    Like on input data, the map should be a square of odd side n with the start in its middle,
+    and its border, the row and column of the start free of rocks, or there is no answer.
+    The elf then walks straight to the copies of the start, and after n/2 + k*n steps the
+    reached area is a diamond made of k*k-ish copies of the map: the count is a quadratic
+    polynomial of k.
+    It is fitted on k = 0, 1, 2 by brute force, and evaluated with Newton's forward differences.
+    */
+    let n = garden.rocks.height();
+    if garden.rocks.width() != n || garden.start != (n/2, n/2) {
+        return Err("the garden is not a square with the start in its middle".to_string());
+    }
+    let (middle, last) = (n/2, n-1);
+    let mut lines = (0..n).flat_map(|i| [(middle, i), (i, middle), (0, i), (last, i), (i, 0), (i, last)]);
+    if lines.any(|cell| garden.rocks[cell]) {
+        return Err("the row and column of the start, or the border of the garden, have rocks".to_string());
+    }
+    if steps < n/2 + 2*n {
+        return Ok(reachable(garden, steps, true) as u64);
+    }
+    if steps % n != n/2 {
+        return Err(format!("{} steps do not end on the edge of a copy of the garden", steps));
+    }
+
+    let f: Vec<u64> = (0..3).map(|k| reachable(garden, n/2 + k*n, true) as u64).collect();
+    let k = (steps / n) as u64;
+    let (d1, d2) = (f[1] - f[0], f[2] + f[0] - 2*f[1]);
+    return Ok(f[0] + d1*k + d2*k*(k-1)/2);
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Garden;
+    type Answer1 = usize;
+    type Answer2 = MaybeAnswer<u64>;
+
+    fn parse(contents: &str) -> Result<Garden, ParseError> {
+        let lines: Vec<Line> = grid_lines(contents)?;
+        let rocks = Grid::from_lines(&lines, |c| match c {
+            '#' => Some(true),
+            '.' | 'S' => Some(false),
+            _ => None,
+        })?;
+
+        let mut start: Option<(usize, usize)> = None;
+        for (i, line) in lines.iter().enumerate() {
+            for (j, (pos, c)) in line.char_indices().enumerate() {
+                if c == 'S' {
+                    if start.is_some() {
+                        return Err(line.error(&line.text[pos..(pos+1)], "found a second starting plot `S`"));
+                    }
+                    start = Some((i, j));
+                }
+            }
+        }
+        let start = start.ok_or_else(|| ParseError::new("no starting plot `S` in the garden"))?;
+
+        return Ok(Garden{rocks, start});
+    }
+
+    fn part1(garden: &Garden) -> usize {
+        reachable(garden, STEPS, false)
+    }
+
+    fn part2(garden: &Garden) -> MaybeAnswer<u64> {
+        reachable_extrapolated(garden, INFINITE_STEPS).into()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    const EXAMPLE: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn test_example() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(garden.start, (5, 5));
+        assert_eq!(reachable(&garden, 6, false), 16);
+    }
+
+    #[test]
+    fn test_example_infinite() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536)] {
+            assert_eq!(reachable(&garden, steps, true), plots);
+        }
+    }
+
+    #[test]
+    fn test_extrapolated() {
+        // the example has rocks in the row of its start, an open garden of the same size
+        let garden = Day21::parse(&EXAMPLE.replace('#', ".")).unwrap();
+        for k in 2..6 {
+            let steps = 5 + k*11;
+            assert_eq!(reachable_extrapolated(&garden, steps), Ok(reachable(&garden, steps, true) as u64));
+        }
+        assert_eq!(reachable_extrapolated(&garden, 5 + 11*100), Ok(1106*1106));
+    }
+
+    #[test]
+    fn test_extrapolated_rocks() {
+        // rocks everywhere but in the row and column of the start, and on the border
+        let garden = Day21::parse("...........
+.#.#...#.#.
+..#....#...
+.#......##.
+....#.#..#.
+.....S.....
+.#.#....#..
+...#...#.#.
+.#.....#...
+..##....#..
+...........
+").unwrap();
+        for k in 2..8 {
+            let steps = 5 + k*11;
+            assert_eq!(reachable_extrapolated(&garden, steps), Ok(reachable(&garden, steps, true) as u64));
+        }
+    }
+
+    #[test]
+    fn test_extrapolated_not_applicable() {
+        let garden = Day21::parse(EXAMPLE).unwrap();
+        assert!(reachable_extrapolated(&garden, 5 + 11*10).is_err());
+        assert_eq!(Day21::part2(&garden).to_string(), "no answer (the row and column of the start, or the border of the garden, have rocks)");
+        // a clear garden, but a step count that ends in the middle of a copy
+        let garden = Day21::parse(&EXAMPLE.replace('#', ".")).unwrap();
+        assert_eq!(reachable_extrapolated(&garden, 20), Ok(21*21));
+        assert!(reachable_extrapolated(&garden, 100).is_err());
+    }
+
+    #[test]
+    fn test_parse_two_starts() {
+        let e = Day21::parse("S.\n.S\n").err().unwrap();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert!(Day21::parse("..\n..\n").is_err());
+    }
+
+    fuzz_parse!(Day21);
+}
@@ -0,0 +1,14 @@
+use std::env;
+use aoc_common::Solution;
+use aoc_common::input::run_main;
+use aoc21::Day21;
+
+fn main() {
+    run_main::<Day21>(env::args().collect(), |input| {
+        let plots = Day21::part1(input);
+        let infinite_plots = Day21::part2(input);
+
+        println!("Garden plots reached in 64 steps: {plots}");
+        println!("Garden plots reached in 26501365 steps on the infinite map: {infinite_plots}");
+    });
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use aoc_common::Generator;
+use aoc_common::rng::Rng;
+use crate::Day22;
+
+impl Generator for Day22 {
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        /*
+        Like the puzzle, bricks of 1 to 5 cubes floating in a narrow column, in no
+        particular order. They never overlap, a brick is drawn again until it fits.
+        */
+        let count = size.max(1) * 5;
+        let height = count;
+        let mut used: HashSet<[usize; 3]> = HashSet::new();
+        let mut res: Vec<String> = vec![];
+        while res.len() < count {
+            let start = [rng.below(10), rng.below(10), 1 + rng.below(height)];
+            let axis = rng.below(3);
+            let mut end = start;
+            end[axis] = (start[axis] + rng.below(5)).min(if axis == 2 { height } else { 9 });
+
+            let cubes: Vec<[usize; 3]> = (start[axis]..=end[axis]).map(|v| { let mut c = start; c[axis] = v; c }).collect();
+            if cubes.iter().any(|c| used.contains(c)) {
+                continue;
+            }
+            used.extend(cubes);
+            res.push(format!("{},{},{}~{},{},{}", start[0], start[1], start[2], end[0], end[1], end[2]));
+        }
+        return res.join("\n") + "\n";
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Solution;
+    use crate::{falling_counts, settle};
+
+    #[test]
+    fn test_generated_inputs() {
+        for seed in 0..10 {
+            let input = Day22::parse(&Day22::generate(&mut Rng::new(seed), 20)).unwrap();
+            // the top brick can always go
+            assert!(Day22::part1(&input) >= 1);
+            Day22::part2(&input);
+        }
+    }
+
+    #[test]
+    fn test_generated_falling_counts() {
+        // the dominator tree against each removal propagated up the stack
+        for seed in 0..10 {
+            let input = Day22::parse(&Day22::generate(&mut Rng::new(seed), 10)).unwrap();
+            let stack = settle(&input);
+            let counts = falling_counts(&stack);
+            for removed in 0..input.len() {
+                let mut fallen = vec![false; input.len()];
+                fallen[removed] = true;
+                for i in stack.order.iter() {
+                    let supports = &stack.supported_by[*i];
+                    if supports.len() > 0 && supports.iter().all(|s| fallen[*s]) {
+                        fallen[*i] = true;
+                    }
+                }
+                assert_eq!(counts[removed], fallen.iter().filter(|f| **f).count() - 1, "removing brick {} of seed {}", removed, seed);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+use aoc_common::{ParseError, Solution};
+use aoc_common::parse::{lines, Line};
+
+mod gen;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Brick {
+    min: [usize; 3], // x, y, z
+    max: [usize; 3],
+}
+
+impl Brick {
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.min[0]..=self.max[0]).flat_map(move |x| (self.min[1]..=self.max[1]).map(move |y| (x, y)))
+    }
+}
+
+struct Stack {
+    supports: Vec<Vec<usize>>,     // the bricks resting on each brick
+    supported_by: Vec<Vec<usize>>, // the bricks each brick rests on, none for the ground
+    order: Vec<usize>,             // bottom to top, every brick after the ones it rests on
+}
+
+fn settle(bricks: &Vec<Brick>) -> Stack {
+    /*
+    The bricks fall in the order of their lowest cube: a brick can only land on the
+    ones below it. A height map keeps the top of the pile over each (x,y), and the brick
+    there: a falling brick stops just over the highest point of its footprint, and
+    rests on every brick reaching that height.
+    */
+    let mut order: Vec<usize> = (0..bricks.len()).collect();
+    order.sort_by_key(|i| bricks[*i].min[2]);
+
+    let mut heights: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut supports: Vec<Vec<usize>> = vec![vec![]; bricks.len()];
+    let mut supported_by: Vec<Vec<usize>> = vec![vec![]; bricks.len()];
+    for i in order.iter() {
+        let brick = &bricks[*i];
+        let top = brick.footprint().map(|xy| heights.get(&xy).map_or(0, |(z, _)| *z)).max().unwrap();
+        for xy in brick.footprint() {
+            if let Some((z, below)) = heights.get(&xy) {
+                if *z == top && !supported_by[*i].contains(below) {
+                    supported_by[*i].push(*below);
+                    supports[*below].push(*i);
+                }
+            }
+        }
+
+        let landed_top = top + 1 + brick.max[2] - brick.min[2];
+        for xy in brick.footprint() {
+            heights.insert(xy, (landed_top, *i));
+        }
+    }
+
+    return Stack{supports, supported_by, order};
+}
+
+fn falling_counts(stack: &Stack) -> Vec<usize> {
+    /*
+    Brick a makes brick b fall when every path from the ground to b goes through a:
+    a dominates b, with the ground as the root. In bottom to top order, the immediate
+    dominator of a brick is the common ancestor of its supports in the dominator tree
+    (the ground for a brick on the ground), and the bricks falling with a are its
+    descendants in that tree.
+    */
+    let count = stack.order.len();
+    let ground = count;
+    let mut parent: Vec<usize> = vec![ground; count+1];
+    let mut depth: Vec<usize> = vec![0; count+1];
+
+    for i in stack.order.iter() {
+        let mut supports = stack.supported_by[*i].iter();
+        let mut dominator = *supports.next().unwrap_or(&ground);
+        for other in supports {
+            let mut other = *other;
+            while dominator != other {
+                if depth[dominator] >= depth[other] {
+                    dominator = parent[dominator];
+                } else {
+                    other = parent[other];
+                }
+            }
+        }
+        parent[*i] = dominator;
+        depth[*i] = depth[dominator] + 1;
+    }
+
+    // subtree sizes, from the top of the pile down
+    let mut falling: Vec<usize> = vec![0; count+1];
+    for i in stack.order.iter().rev() {
+        falling[parent[*i]] += falling[*i] + 1;
+    }
+    falling.truncate(count);
+    return falling;
+}
+
+fn parse_corner(line: &Line, s: &str) -> Result<[usize; 3], ParseError> {
+    let coords: Vec<&str> = s.split(',').collect();
+    if coords.len() != 3 {
+        return Err(line.error(s, &format!("expected 3 coordinates `x,y,z`, found {}", coords.len())));
+    }
+    let mut res = [0; 3];
+    for (i, coord) in coords.iter().enumerate() {
+        res[i] = line.number::<usize>(coord)?;
+    }
+    return Ok(res);
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<Brick>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(contents: &str) -> Result<Vec<Brick>, ParseError> {
+        let mut bricks: Vec<Brick> = vec![];
+        for line in lines(contents) {
+            if line.len() == 0 {
+                continue;
+            }
+            let (start_str, end_str) = line.split_once('~').ok_or_else(|| line.error_line("expected a brick `x,y,z~x,y,z`"))?;
+            let (start, end) = (parse_corner(&line, start_str)?, parse_corner(&line, end_str)?);
+
+            // a single line of cubes, above the ground at z=0
+            if (0..3).filter(|i| start[*i] != end[*i]).count() > 1 {
+                return Err(line.error_line("a brick should be a single line of cubes"));
+            }
+            if start[2].min(end[2]) == 0 {
+                return Err(line.error_line("a brick cannot be in the ground, at z=0"));
+            }
+            let min = [start[0].min(end[0]), start[1].min(end[1]), start[2].min(end[2])];
+            let max = [start[0].max(end[0]), start[1].max(end[1]), start[2].max(end[2])];
+            bricks.push(Brick{min, max});
+        }
+        return Ok(bricks);
+    }
+
+    fn part1(bricks: &Vec<Brick>) -> usize {
+        // bricks whose every brick on top rests on another one too
+        let stack = settle(bricks);
+        return (0..bricks.len())
+            .filter(|i| stack.supports[*i].iter().all(|above| stack.supported_by[*above].len() > 1))
+            .count();
+    }
+
+    fn part2(bricks: &Vec<Brick>) -> usize {
+        let stack = settle(bricks);
+        return falling_counts(&stack).iter().sum();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz_parse;
+
+    const EXAMPLE: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
+
+    #[test]
+    fn test_example() {
+        let bricks = Day22::parse(EXAMPLE).unwrap();
+        assert_eq!(Day22::part1(&bricks), 5);
+        assert_eq!(Day22::part2(&bricks), 7);
+    }
+
+    #[test]
+    fn test_falling_counts() {
+        let stack = settle(&Day22::parse(EXAMPLE).unwrap());
+        assert_eq!(stack.supported_by[6], vec![5]);
+        assert_eq!(falling_counts(&stack), vec![6, 0, 0, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Day22::parse("1,0,1~1,2,1\n0,0,2~2,0\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.len), (2, 7, 3));
+        let e = Day22::parse("1,0,1~1,2,2\n").err().unwrap();
+        assert!(e.message.contains("single line"));
+        assert!(Day22::parse("1,0,0~1,2,0\n").is_err());
+    }
+
+    fuzz_parse!(Day22);
+}
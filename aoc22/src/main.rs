@@ -0,0 +1,14 @@
+use std::env;
+use aoc_common::Solution;
+use aoc_common::input::run_main;
+use aoc22::Day22;
+
+fn main() {
+    run_main::<Day22>(env::args().collect(), |input| {
+        let safe = Day22::part1(input);
+        let falls = Day22::part2(input);
+
+        println!("Bricks safe to disintegrate: {safe}");
+        println!("Sum of the bricks falling after each disintegration: {falls}");
+    });
+}